@@ -1,7 +1,10 @@
+use std::{cell::OnceCell, marker::PhantomData, ops::Range, rc::{Rc, Weak}, sync::Arc};
+
 use crate::{
-    errors::{EitherError, ParsingError, ParsingErrorKind},
-    parsers::{parse_many, ParserInput, ParserResult},
-    traits::{Parser, ParserError},
+    errors::{ParsingError, ParsingErrorKind},
+    macros::tuple_parsers,
+    parsers::{parse_fold, parse_many, BoxedParser, Eof, ParserInput, ParserResult, Spanned},
+    traits::{Input, InputEnd, InputError, InputSlice, LookaheadError, Parser, ParserError, PermutationMember},
 };
 
 pub struct AndThen<P1, P2> {
@@ -45,6 +48,175 @@ where
     }
 }
 
+pub fn preceded<P1, P2>(first: P1, second: P2) -> 
+impl Parser<
+    Input = P1::Input,
+    Output = P2::Output,
+    Error = P1::Error
+>
+where
+    P1: Parser,
+    P2: Parser<Input = P1::Input>,
+    P2::Error: Into<P1::Error>
+{
+    first.and_then(second)
+        .map(|(_, output)| output)
+}
+
+pub fn terminated<P1, P2>(first: P1, second: P2) -> 
+impl Parser<
+    Input = P1::Input,
+    Output = P1::Output,
+    Error = P1::Error
+>
+where
+    P1: Parser,
+    P2: Parser<Input = P1::Input>,
+    P2::Error: Into<P1::Error>
+{
+    first.and_then(second)
+        .map(|(output, _)| output)
+}
+
+pub fn delimited<Open, P, Close>(open: Open, p: P, close: Close) -> 
+impl Parser<
+    Input = Open::Input,
+    Output = P::Output,
+    Error = Open::Error
+>
+where
+    Open: Parser,
+    P: Parser<Input = Open::Input>,
+    P::Error: Into<Open::Error>,
+    Close: Parser<Input = Open::Input>,
+    Close::Error: Into<Open::Error>
+{
+    open.and_then(p)
+        .and_then(close)
+        .map(|((_, output), _)| output)
+}
+
+pub struct SurroundedBy<P, S> {
+    pub(crate) p: P,
+    pub(crate) surrounding: S
+}
+
+impl<P, S> Parser for SurroundedBy<P, S> 
+where
+    P: Parser,
+    S: Parser<Input = P::Input>,
+    S::Error: Into<P::Error>
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let (rest, _) = self.surrounding.parse(input)
+            .map_err(|(input, error)| (input, error.into()))?;
+        let (rest, output) = self.p.parse(rest)?;
+        let (rest, _) = self.surrounding.parse(rest)
+            .map_err(|(input, error)| (input, error.into()))?;
+
+        Ok((rest, output))
+    }
+}
+
+pub fn not<P>(p: P) -> Not<P>
+where
+    P: Parser,
+    P::Input: InputSlice + Clone,
+    P::Error: LookaheadError<P::Input>
+{
+    Not(p)
+}
+
+pub struct Not<P> (pub(crate) P);
+
+impl<P> Parser for Not<P> 
+where
+    P: Parser,
+    P::Input: InputSlice + Clone,
+    P::Error: LookaheadError<P::Input>
+{
+    type Input = P::Input;
+    type Output = ();
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        match self.0.parse(input.clone()) {
+            Ok((rest, _)) => {
+                let found = input.data.consumed(&rest.data);
+                let position = input.position;
+                Err((
+                    input,
+                    ParsingError {
+                        error: P::Error::unexpected_match(found).into(),
+                        position
+                    }
+                ))
+            }
+            Err((input, error)) if !error.is_recoverable() => Err((input, error)),
+            Err(_) => Ok((input, ()))
+        }
+    }
+}
+
+pub struct AllConsuming<P> (pub(crate) P);
+
+impl<P> Parser for AllConsuming<P> 
+where
+    P: Parser,
+    P::Input: InputEnd + Clone,
+    P::Error: InputError<P::Input>
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let (rest, output) = self.0.parse(input)?;
+        let (rest, _) = Eof { _marker: PhantomData }.parse(rest)?;
+        Ok((rest, output))
+    }
+}
+
+pub struct Cut<P> (pub(crate) P);
+
+impl<P> Parser for Cut<P> 
+where P: Parser
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        self.0.parse(input)
+        .map_err(|(input, ParsingError { error, position })|
+            (input, ParsingError { error: error.cut(), position })
+        )
+    }
+}
+
+pub struct Peek<P> (pub(crate) P);
+
+impl<P> Parser for Peek<P> 
+where
+    P: Parser,
+    P::Input: Clone
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        match self.0.parse(input.clone()) {
+            Ok((_, output)) => Ok((input, output)),
+            Err((_, error)) => Err((input, error))
+        }
+    }
+}
+
 pub struct OrElse<P1, P2> {
     pub(crate) p1: P1,
     pub(crate) p2: P2,
@@ -64,12 +236,16 @@ where
         self
         .p1
         .parse(input)
-        .or_else(|(input, error)|
+        .or_else(|(input, error)| {
+            if !error.is_recoverable() {
+                return Err((input, error.into()));
+            }
+
             self.p2.parse(input)
             .map_err(|(input, error2)|
                 (input, error.into().append(error2))
             )
-        )
+        })
     }
 }
 
@@ -117,12 +293,18 @@ where
             .map_err(|(input, error)| {
                 let ParsingError {
                     error,
-                    index
+                    position
                 } = error;
 
+                let error = match error {
+                    ParsingErrorKind::Incomplete { needed } => ParsingErrorKind::Incomplete { needed },
+                    ParsingErrorKind::Cut(error) => ParsingErrorKind::Cut(Box::new((self.err_mapper)(*error).into())),
+                    error => (self.err_mapper)(error).into()
+                };
+
                 let new_error = ParsingError {
-                    error: (self.err_mapper)(error).into(),
-                    index
+                    error,
+                    position
                 };
 
                 (input, new_error)
@@ -156,7 +338,7 @@ where
                     input.clone(),
                     ParsingError {
                         error: error.into(),
-                        index: input.index
+                        position: input.position
                     }
                 ))
             )
@@ -175,15 +357,11 @@ where P: Parser
     type Error = P::Error;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        self.p.parse(input)
-            .map_or_else(|error| Err(error),
-            |(rest, output)|
-                Ok(parse_many(&self.p, rest))
-                .map(|(rest, mut result)|{
-                    result.insert(0, output);
-                    (rest, result)
-                })
-            )
+        let (rest, output) = self.p.parse(input)?;
+        parse_fold(&self.p, rest, vec![output], |mut result, output| {
+            result.push(output);
+            result
+        })
     }
 }
 
@@ -200,7 +378,140 @@ where P: Parser
     type Error = P::Error;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        Ok(parse_many(&self.p, input))
+        parse_many(&self.p, input)
+    }
+}
+
+pub struct FoldMany<P, R, F> {
+    pub(crate) p: P,
+    pub(crate) init: R,
+    pub(crate) folder: F
+}
+
+impl<P, R, F> Parser for FoldMany<P, R, F> 
+where
+    P: Parser,
+    R: Clone,
+    F: Fn(R, P::Output) -> R
+{
+    type Input = P::Input;
+    type Output = R;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        parse_fold(&self.p, input, self.init.clone(), &self.folder)
+    }
+}
+
+pub struct FoldMany1<P, R, F> {
+    pub(crate) p: P,
+    pub(crate) init: R,
+    pub(crate) folder: F
+}
+
+impl<P, R, F> Parser for FoldMany1<P, R, F> 
+where
+    P: Parser,
+    R: Clone,
+    F: Fn(R, P::Output) -> R
+{
+    type Input = P::Input;
+    type Output = R;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let (rest, output) = self.p.parse(input)?;
+        let init = (self.folder)(self.init.clone(), output);
+        parse_fold(&self.p, rest, init, &self.folder)
+    }
+}
+
+pub struct ManyInto<P, C> {
+    pub(crate) p: P,
+    pub(crate) _marker: PhantomData<C>
+}
+
+impl<P, C> Parser for ManyInto<P, C> 
+where
+    P: Parser,
+    C: Default + Extend<P::Output>
+{
+    type Input = P::Input;
+    type Output = C;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        parse_fold(&self.p, input, C::default(), |mut collection, output| {
+            collection.extend(std::iter::once(output));
+            collection
+        })
+    }
+}
+
+pub struct Repeat<P> {
+    pub(crate) p: P,
+    pub(crate) min: usize,
+    pub(crate) max: Option<usize>
+}
+
+impl<P> Parser for Repeat<P> 
+where P: Parser
+{
+    type Input = P::Input;
+    type Output = Vec<P::Output>;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let mut result = Vec::new();
+        let mut current_input = input;
+        while self.max.is_none_or(|max| result.len() < max) {
+            match self.p.parse(current_input) {
+                Ok((rest, output)) => {
+                    result.push(output);
+                    current_input = rest;
+                }
+                Err((input, error)) if !error.is_recoverable() || result.len() < self.min => {
+                    return Err((input, error))
+                }
+                Err((input, _)) => return Ok((input, result))
+            }
+        }
+
+        debug_assert!(result.len() >= self.min, "repeat stopped below its minimum");
+        Ok((current_input, result))
+    }
+}
+
+pub struct ManyTill<P, End> {
+    pub(crate) p: P,
+    pub(crate) end: End
+}
+
+impl<P, End> Parser for ManyTill<P, End> 
+where
+    P: Parser,
+    P::Input: Clone,
+    End: Parser<Input = P::Input>,
+    End::Error: Into<P::Error>
+{
+    type Input = P::Input;
+    type Output = (Vec<P::Output>, End::Output);
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let mut result = Vec::new();
+        let mut current_input = input;
+        loop {
+            match self.end.parse(current_input.clone()) {
+                Ok((rest, terminator)) => return Ok((rest, (result, terminator))),
+                Err((input, error)) if !error.is_recoverable() => return Err((input, error.into())),
+                Err(_) => {}
+            }
+
+            let (rest, output) = self.p.parse(current_input)?;
+            result.push(output);
+            current_input = rest;
+        }
     }
 }
 
@@ -223,7 +534,6 @@ where
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
         self.p
         .parse(input.clone())
-        .map_err(|(input, error)| (input, error))
         .and_then(|(rest, output)| {
             if (self.pred)(&output) {
                 Ok((rest, output))
@@ -233,7 +543,7 @@ where
                     input.clone(),
                     ParsingError {
                         error: ParsingErrorKind::VerifyError,
-                        index: input.index
+                        position: input.position
                     } 
                     
                 ))
@@ -255,7 +565,13 @@ where
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
         self.0.parse(input)
         .map_or_else(
-            |(input, _)| Ok((input, None)),
+            |(input, error)| 
+                if !error.is_recoverable() {
+                    Err((input, error))
+                }
+                else {
+                    Ok((input, None))
+                },
             |(rest, output)| Ok((rest, Some(output)))
         )
 
@@ -281,10 +597,7 @@ where
     }
 }
 
-pub struct SepBy<P, SepP> {
-    pub(crate) p: P,
-    pub(crate) separator: SepP
-}
+pub struct SepBy<P, SepP> (pub(crate) SepByWithSeparators<P, SepP>);
 
 impl<P, SepP> Parser for SepBy<P, SepP> 
 where
@@ -296,34 +609,533 @@ where
     type Error = P::Error;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        let mut result = Vec::new();
-        let (rest, output) = self.p.parse(input)?;
+        self.0.parse_separated(input, |_| {})
+    }
+}
+
+pub struct SepByWithSeparators<P, SepP> {
+    pub(crate) p: P,
+    pub(crate) separator: SepP,
+    pub(crate) allow_empty: bool,
+    pub(crate) allow_trailing: bool
+}
 
-        result.push(output);
+impl<P, SepP> SepByWithSeparators<P, SepP> 
+where
+    P: Parser,
+    SepP: Parser<Input = P::Input>
+{
+    // An empty list or a trailing separator is only accepted when the element parser
+    // fails without consuming anything, otherwise its error is reported
+    fn parse_separated<F>(&self, input: ParserInput<P::Input>, mut on_separator: F)
+        -> ParserResult<P::Input, Vec<P::Output>, P::Error>
+    where F: FnMut(SepP::Output)
+    {
+        let mut result = Vec::new();
 
-        let mut current_input = rest;
+        let offset = input.position.offset;
+        let mut current_input = match self.p.parse(input) {
+            Ok((rest, output)) => {
+                result.push(output);
+                rest
+            }
+            Err((input, error))
+                if self.allow_empty && error.is_recoverable() && input.position.offset == offset =>
+                return Ok((input, result)),
+            Err(error) => return Err(error)
+        };
 
         loop {
-            let out_res = 
-            self.separator.parse(current_input)
-            .map_err(|(input, error)|
-                (input, EitherError::LeftError(error))
-            )
-            .and_then(|(rest, _)|
-                self.p.parse(rest)
-                .map_err(|(input, error)| (input, EitherError::RightError(error)))
-            );
+            let (rest, sep_output) = match self.separator.parse(current_input) {
+                Ok(parsed) => parsed,
+                Err((input, error)) => return match error.into_unrecoverable() {
+                    Some(error) => Err((input, error)),
+                    None => Ok((input, result))
+                }
+            };
 
-            match out_res {
+            let offset = rest.position.offset;
+            on_separator(sep_output);
+
+            match self.p.parse(rest) {
                 Ok((rest, output)) => {
                     result.push(output);
                     current_input = rest;
                 }
+                Err((input, error))
+                    if self.allow_trailing && error.is_recoverable() && input.position.offset == offset =>
+                    return Ok((input, result)),
+                Err(error) => return Err(error)
+            }
+        }
+    }
+}
 
-                Err((input, EitherError::LeftError(_))) => return Ok((input, result)),
-                Err((input, EitherError::RightError(error))) =>
-                    return Err((input, error))
+impl<P, SepP> Parser for SepByWithSeparators<P, SepP> 
+where
+    P: Parser,
+    SepP: Parser<Input = P::Input>
+{
+    type Input = P::Input;
+    type Output = (Vec<P::Output>, Vec<SepP::Output>);
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let mut separators = Vec::new();
+        let (rest, result) = self.parse_separated(input, |separator| separators.push(separator))?;
+
+        Ok((rest, (result, separators)))
+    }
+}
+
+pub struct ChainLeft<P, OpP> {
+    pub(crate) p: P,
+    pub(crate) op: OpP
+}
+
+impl<P, OpP> Parser for ChainLeft<P, OpP> 
+where
+    P: Parser,
+    OpP: Parser<Input = P::Input>,
+    OpP::Error: Into<P::Error>,
+    OpP::Output: Fn(P::Output, P::Output) -> P::Output
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let (mut current_input, mut result) = self.p.parse(input)?;
+        loop {
+            let (rest, op) = match parse_operator(&self.op, current_input)? {
+                (rest, Some(op)) => (rest, op),
+                (rest, None) => return Ok((rest, result))
+            };
+
+            let (rest, rhs) = self.p.parse(rest)?;
+            result = op(result, rhs);
+            current_input = rest;
+        }
+    }
+}
+
+pub struct ChainRight<P, OpP> {
+    pub(crate) p: P,
+    pub(crate) op: OpP
+}
+
+impl<P, OpP> Parser for ChainRight<P, OpP> 
+where
+    P: Parser,
+    OpP: Parser<Input = P::Input>,
+    OpP::Error: Into<P::Error>,
+    OpP::Output: Fn(P::Output, P::Output) -> P::Output
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    // The operands are kept with the operator following them, and folded from the right at the end
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let (mut current_input, mut last) = self.p.parse(input)?;
+        let mut pending = Vec::new();
+        loop {
+            let (rest, op) = match parse_operator(&self.op, current_input)? {
+                (rest, Some(op)) => (rest, op),
+                (rest, None) => {
+                    current_input = rest;
+                    break;
+                }
+            };
+
+            let (rest, rhs) = self.p.parse(rest)?;
+            pending.push((last, op));
+            last = rhs;
+            current_input = rest;
+        }
+
+        let result = pending
+            .into_iter()
+            .rev()
+            .fold(last, |rhs, (lhs, op)| op(lhs, rhs));
+
+        Ok((current_input, result))
+    }
+}
+
+// A failing operator ends the chain, unless it needs more input or was cut
+fn parse_operator<OpP, E>(op: &OpP, input: ParserInput<OpP::Input>)
+    -> ParserResult<OpP::Input, Option<OpP::Output>, E>
+where
+    OpP: Parser,
+    OpP::Error: Into<E>,
+    E: ParserError
+{
+    match op.parse(input) {
+        Ok((rest, op)) => Ok((rest, Some(op))),
+        Err((input, error)) if !error.is_recoverable() => Err((input, error.into())),
+        Err((input, _)) => Ok((input, None))
+    }
+}
+
+pub struct WithSpan<P> (pub(crate) P);
+
+impl<P> Parser for WithSpan<P> 
+where
+    P: Parser
+{
+    type Input = P::Input;
+    type Output = Spanned<P::Output>;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let start = input.position.offset;
+        self.0.parse(input)
+        .map(|(rest, value)| {
+            let span = start..rest.position.offset;
+            (rest, Spanned { value, span })
+        })
+    }
+}
+
+pub struct Recognize<P> (pub(crate) P);
+
+impl<P> Parser for Recognize<P> 
+where
+    P: Parser,
+    P::Input: Input
+{
+    type Input = P::Input;
+    type Output = P::Input;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let data = input.data.clone();
+        self.0.parse(input)
+        .map(|(rest, _)| {
+            let (consumed, _) = data.take_split(data.offset(&rest.data));
+            (rest, consumed)
+        })
+    }
+}
+
+pub struct MapWithSpan<P, F> {
+    pub(crate) p: P,
+    pub(crate) mapper: F
+}
+
+impl<P, F, R> Parser for MapWithSpan<P, F> 
+where
+    P: Parser,
+    F: Fn(P::Output, Range<usize>) -> R
+{
+    type Input = P::Input;
+    type Output = R;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let start = input.position.offset;
+        self.p
+            .parse(input)
+            .map(|(rest, output)| {
+                let span = start..rest.position.offset;
+                (rest, (self.mapper)(output, span))
+            })
+    }
+}
+
+pub fn alt<T>(parsers: T) -> Alt<T> {
+    Alt(parsers)
+}
+
+pub struct Alt<T> (pub(crate) T);
+
+pub fn permutation<T>(members: T) -> Permutation<T> {
+    Permutation(members)
+}
+
+pub struct Permutation<T> (pub(crate) T);
+
+pub fn member<P: Parser>(name: &'static str, p: P) -> Member<P> {
+    Member { name, p }
+}
+
+pub fn optional_member<P: Parser>(name: &'static str, p: P) -> OptionalMember<P> {
+    OptionalMember { name, p }
+}
+
+pub struct Member<P> {
+    pub(crate) name: &'static str,
+    pub(crate) p: P
+}
+
+impl<P: Parser> PermutationMember for Member<P> {
+    type Parser = P;
+    type Output = P::Output;
+
+    fn parser(&self) -> &Self::Parser {
+        &self.p
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn is_required(&self) -> bool {
+        true
+    }
+
+    fn finish(&self, output: Option<P::Output>) -> Self::Output {
+        output.expect("required permutation members are checked before finishing")
+    }
+}
+
+pub struct OptionalMember<P> {
+    pub(crate) name: &'static str,
+    pub(crate) p: P
+}
+
+impl<P: Parser> PermutationMember for OptionalMember<P> {
+    type Parser = P;
+    type Output = Option<P::Output>;
+
+    fn parser(&self) -> &Self::Parser {
+        &self.p
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn is_required(&self) -> bool {
+        false
+    }
+
+    fn finish(&self, output: Option<P::Output>) -> Self::Output {
+        output
+    }
+}
+
+pub fn choice<P: Parser>(parsers: Vec<P>) -> Choice<P> {
+    Choice { parsers }
+}
+
+pub struct Choice<P> {
+    pub(crate) parsers: Vec<P>
+}
+
+impl<P> Parser for Choice<P> 
+where
+    P: Parser,
+    P::Input: Clone
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let mut error: Option<ParsingError<P::Error>> = None;
+        for parser in &self.parsers {
+            match parser.parse(input.clone()) {
+                Err((_, new_error)) if new_error.is_recoverable() => {
+                    error = Some(match error {
+                        Some(error) => error.append(new_error),
+                        None => new_error
+                    });
+                }
+                result => return result,
             }
         }
+
+        // Without any parser to try, the choice fails like a failed verify
+        let error = error.unwrap_or(ParsingError {
+            error: ParsingErrorKind::VerifyError,
+            position: input.position
+        });
+
+        Err((input, error))
+    }
+}
+
+pub fn recursive<'p, I, O, E, F, P>(builder: F) -> Recursive<'p, I, O, E>
+where
+    F: FnOnce(Recursive<'p, I, O, E>) -> P,
+    P: Parser<Input = I, Output = O, Error = E> + 'p,
+    E: ParserError
+{
+    let cell = Rc::new(OnceCell::new());
+    let this = Recursive(RecursiveRef::Weak(Rc::downgrade(&cell)));
+    let parser: BoxedParser<'p, I, O, E> = Box::new(builder(this));
+    if cell.set(parser).is_err() {
+        unreachable!("recursive parser is only defined once");
+    }
+
+    Recursive(RecursiveRef::Strong(cell))
+}
+
+type RecursiveCell<'p, I, O, E> = OnceCell<BoxedParser<'p, I, O, E>>;
+
+// The handle given to the builder is weak, so that the parser does not own itself
+enum RecursiveRef<'p, I, O, E> {
+    Strong(Rc<RecursiveCell<'p, I, O, E>>),
+    Weak(Weak<RecursiveCell<'p, I, O, E>>)
+}
+
+pub struct Recursive<'p, I, O, E> (RecursiveRef<'p, I, O, E>);
+
+impl<'p, I, O, E> Clone for Recursive<'p, I, O, E> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            RecursiveRef::Strong(cell) => Recursive(RecursiveRef::Strong(cell.clone())),
+            RecursiveRef::Weak(cell) => Recursive(RecursiveRef::Weak(cell.clone()))
+        }
     }
-}
\ No newline at end of file
+}
+
+impl<'p, I, O, E> Parser for Recursive<'p, I, O, E> 
+where E: ParserError
+{
+    type Input = I;
+    type Output = O;
+    type Error = E;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let cell = match &self.0 {
+            RecursiveRef::Strong(cell) => cell.clone(),
+            RecursiveRef::Weak(cell) => cell.upgrade()
+                .expect("recursive parser used after it was dropped")
+        };
+
+        cell.get()
+            .expect("recursive parser used before it was defined")
+            .parse(input)
+    }
+}
+
+pub fn from_fn<I, O, E, F>(f: F) -> FromFn<F, I>
+where
+    F: Fn(ParserInput<I>) -> ParserResult<I, O, E>,
+    E: ParserError
+{
+    FromFn {
+        f,
+        _marker: PhantomData
+    }
+}
+
+pub struct FromFn<F, I> {
+    pub(crate) f: F,
+    pub(crate) _marker: PhantomData<I>
+}
+
+impl<F, I, O, E> Parser for FromFn<F, I> 
+where
+    F: Fn(ParserInput<I>) -> ParserResult<I, O, E>,
+    E: ParserError
+{
+    type Input = I;
+    type Output = O;
+    type Error = E;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        (self.f)(input)
+    }
+}
+
+pub fn lazy<F, P>(builder: F) -> Lazy<F, P>
+where
+    F: Fn() -> P,
+    P: Parser
+{
+    Lazy {
+        builder,
+        parser: OnceCell::new()
+    }
+}
+
+pub struct Lazy<F, P> {
+    pub(crate) builder: F,
+    pub(crate) parser: OnceCell<P>
+}
+
+impl<F, P> Parser for Lazy<F, P> 
+where
+    F: Fn() -> P,
+    P: Parser
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        self.parser
+            .get_or_init(&self.builder)
+            .parse(input)
+    }
+}
+
+impl<P> Parser for Box<P> 
+where
+    P: Parser + ?Sized
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        (**self).parse(input)
+    }
+}
+
+impl<P> Parser for &P 
+where
+    P: Parser + ?Sized
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        (**self).parse(input)
+    }
+}
+
+impl<P> Parser for Rc<P> 
+where
+    P: Parser + ?Sized
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        (**self).parse(input)
+    }
+}
+
+impl<P> Parser for Arc<P> 
+where
+    P: Parser + ?Sized
+{
+    type Input = P::Input;
+    type Output = P::Output;
+    type Error = P::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        (**self).parse(input)
+    }
+}
+
+tuple_parsers! {
+    P1 P2,
+    P1 P2 P3,
+    P1 P2 P3 P4,
+    P1 P2 P3 P4 P5,
+    P1 P2 P3 P4 P5 P6,
+    P1 P2 P3 P4 P5 P6 P7,
+    P1 P2 P3 P4 P5 P6 P7 P8,
+    P1 P2 P3 P4 P5 P6 P7 P8 P9,
+    P1 P2 P3 P4 P5 P6 P7 P8 P9 P10,
+    P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11,
+    P1 P2 P3 P4 P5 P6 P7 P8 P9 P10 P11 P12
+}
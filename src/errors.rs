@@ -1,4 +1,6 @@
-use crate::traits::ParserError;
+use std::{fmt::{Debug, Display}, io::{self, Read}};
+
+use crate::{parsers::{reader::ReaderInput, Position}, traits::{ExprError, Input, InputError, LookaheadError, NumberError, ParserError, PermutationError, TagError}};
 
 #[derive(Debug)]
 pub enum EitherError<E1, E2> {
@@ -22,9 +24,19 @@ impl<E1, E2> EitherError<E1, E2> {
     } 
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Needed {
+    Unknown,
+    Size(usize),
+}
+
+// A cut error was raised after the parser committed to a branch,
+// so combinators must not backtrack over it to try something else
 #[derive(Debug)]
 pub enum ParsingErrorKind<E> {
     VerifyError,
+    Incomplete{needed: Needed},
+    Cut(Box<ParsingErrorKind<E>>),
     Custom(E),
 }
 
@@ -34,25 +46,61 @@ impl<E: ParserError> ParsingErrorKind<E> {
     {
         match self {
             Self::VerifyError => ParsingErrorKind::VerifyError,
+            Self::Incomplete { needed } => ParsingErrorKind::Incomplete { needed },
+            Self::Cut(error) => ParsingErrorKind::Cut(Box::new((*error).into())),
             Self::Custom(e) => ParsingErrorKind::Custom(e.into()),
         }
     }
 
     pub fn map<F, R>(self, f: F) -> ParsingErrorKind<R> 
+    where
+        F: Fn(E) -> R,
+        R: ParserError
+    {
+        self.map_ref(&f)
+    }
+
+    fn map_ref<F, R>(self, f: &F) -> ParsingErrorKind<R> 
     where
         F: Fn(E) -> R,
         R: ParserError
     {
         match self {
             ParsingErrorKind::Custom(e) => ParsingErrorKind::Custom(f(e)),
+            ParsingErrorKind::Incomplete { needed } => ParsingErrorKind::Incomplete { needed },
+            ParsingErrorKind::Cut(error) => ParsingErrorKind::Cut(Box::new(error.map_ref(f))),
             ParsingErrorKind::VerifyError => ParsingErrorKind::VerifyError
         }
     }
+
+    pub fn cut(self) -> Self {
+        match self {
+            error @ (Self::Incomplete { .. } | Self::Cut(_)) => error,
+            error => Self::Cut(Box::new(error))
+        }
+    }
+
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, Self::Incomplete { .. })
+    }
+
+    pub fn is_cut(&self) -> bool {
+        matches!(self, Self::Cut(_))
+    }
+
+    // Whether a combinator may recover from the error by trying something else
+    pub fn is_recoverable(&self) -> bool {
+        !self.is_incomplete() && !self.is_cut()
+    }
 }
 
 impl<E: ParserError> ParserError for ParsingErrorKind<E> {
     fn append(self, new: Self) -> Self {
         match (self, new) {
+            (incomplete @ Self::Incomplete { .. }, _) => incomplete,
+            (_, incomplete @ Self::Incomplete { .. }) => incomplete,
+            (cut @ Self::Cut(_), _) => cut,
+            (_, cut @ Self::Cut(_)) => cut,
             (Self::Custom(e), Self::Custom(new_e)) => Self::Custom(e.append(new_e)),
             (Self::Custom(e), _) => Self::Custom(e),
             (_, new) => new
@@ -69,49 +117,130 @@ impl<E: ParserError> From<E> for ParsingErrorKind<E> {
 #[derive(Debug)]
 pub struct ParsingError<E> {
     pub error: ParsingErrorKind<E>,
-    pub index: usize
+    pub position: Position
 }
 
 impl<E: ParserError> ParsingError<E> {
+    pub fn is_incomplete(&self) -> bool {
+        self.error.is_incomplete()
+    }
+
+    pub fn is_cut(&self) -> bool {
+        self.error.is_cut()
+    }
+
+    pub fn is_recoverable(&self) -> bool {
+        self.error.is_recoverable()
+    }
+
     pub fn into<E2: ParserError>(self) -> ParsingError<E2>
     where E: Into<E2>
     {
         let Self {
             error,
-            index
+            position
         } = self;
 
         ParsingError {
             error: error.into(),
-            index
+            position
         }
     }
+
+    // For SepBy, which cannot convert the errors of its separator: only the errors
+    // that must not be backtracked over are kept, without their custom error
+    pub(crate) fn into_unrecoverable<E2: ParserError>(self) -> Option<ParsingError<E2>> {
+        let error = match self.error {
+            ParsingErrorKind::Incomplete { needed } => ParsingErrorKind::Incomplete { needed },
+            ParsingErrorKind::Cut(_) => ParsingErrorKind::Cut(Box::new(ParsingErrorKind::VerifyError)),
+            _ => return None
+        };
+
+        Some(ParsingError {
+            error,
+            position: self.position
+        })
+    }
 }
 
 impl<E: ParserError> ParserError for ParsingError<E> {
     fn append(self, new: Self) -> Self {
         let Self {
             error,
-            index
+            position
         } = self;
 
         Self {
             error: error.append(new.error),
-            index
+            position
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseReportKind {
+    TrailingInput,
+    Incomplete{needed: Needed},
+    VerifyError,
+    Custom(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseReport {
+    pub kind: ParseReportKind,
+    pub position: Position
+}
+
+impl<E: Debug> From<ParsingErrorKind<E>> for ParseReportKind {
+    fn from(value: ParsingErrorKind<E>) -> Self {
+        match value {
+            ParsingErrorKind::VerifyError => ParseReportKind::VerifyError,
+            ParsingErrorKind::Incomplete { needed } => ParseReportKind::Incomplete { needed },
+            ParsingErrorKind::Cut(error) => (*error).into(),
+            ParsingErrorKind::Custom(e) => ParseReportKind::Custom(format!("{e:?}")),
+        }
+    }
+}
+
+impl<E: Debug> From<ParsingError<E>> for ParseReport {
+    fn from(value: ParsingError<E>) -> Self {
+        Self {
+            kind: value.error.into(),
+            position: value.position
+        }
+    }
+}
+
+impl Display for ParseReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseReportKind::TrailingInput => write!(f, "unexpected trailing input at {}", self.position),
+            ParseReportKind::Incomplete { .. } => write!(f, "unexpected end of input at {}", self.position),
+            ParseReportKind::VerifyError => write!(f, "verification failed at {}", self.position),
+            ParseReportKind::Custom(message) => write!(f, "{message} at {}", self.position),
+        }
+    }
+}
+
+impl std::error::Error for ParseReport {}
+
 #[derive(Debug)]
 pub enum StrParsingErrorKind<'a> {
     UnexpectedEnd,
     UnexpectedChar{expected: char, found: char},
     UnexpectedString{expected: &'a str, found: &'a str},
+    UnexpectedMatch{found: &'a str},
+    ExpectedEnd{found: &'a str},
     
     
     ExpectingDigit,
     NumberOverflow,
     InvalidFloat,
+
+    DanglingOperator,
+    NonAssociativeOperator,
+
+    MissingMembers{names: Vec<&'static str>},
 }
 
 #[derive(Debug)]
@@ -135,3 +264,337 @@ impl<'a> ParserError for StrParsingErrors<'a> {
         Self {errors}
     }
 }
+
+impl<'a> InputError<&'a str> for StrParsingErrors<'a> {
+    fn unexpected_end() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::UnexpectedEnd)
+    }
+
+    fn expected_end(found: &'a str) -> Self {
+        Self::with_error_kind(StrParsingErrorKind::ExpectedEnd { found })
+    }
+}
+
+impl<'a> TagError<&'a str, &'a str> for StrParsingErrors<'a> {
+    fn unexpected_tag(expected: &'a str, found: &'a str) -> Self {
+        Self::with_error_kind(StrParsingErrorKind::UnexpectedString { expected, found })
+    }
+}
+
+impl<'a> LookaheadError<&'a str> for StrParsingErrors<'a> {
+    fn unexpected_match(found: &'a str) -> Self {
+        Self::with_error_kind(StrParsingErrorKind::UnexpectedMatch { found })
+    }
+}
+
+impl<'a> NumberError for StrParsingErrors<'a> {
+    fn expecting_digit() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::ExpectingDigit)
+    }
+
+    fn number_overflow() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::NumberOverflow)
+    }
+
+    fn invalid_float() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::InvalidFloat)
+    }
+}
+
+impl<'a> ExprError for StrParsingErrors<'a> {
+    fn dangling_operator() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::DanglingOperator)
+    }
+
+    fn non_associative_operator() -> Self {
+        Self::with_error_kind(StrParsingErrorKind::NonAssociativeOperator)
+    }
+}
+
+impl<'a> PermutationError for StrParsingErrors<'a> {
+    fn missing_members(names: Vec<&'static str>) -> Self {
+        Self::with_error_kind(StrParsingErrorKind::MissingMembers { names })
+    }
+}
+
+#[derive(Debug)]
+pub enum BytesParsingErrorKind<'a> {
+    UnexpectedEnd,
+    UnexpectedBytes{expected: &'a [u8], found: &'a [u8]},
+    UnexpectedMatch{found: &'a [u8]},
+    ExpectedEnd{found: &'a [u8]},
+    InvalidUtf8,
+
+    ExpectingDigit,
+    NumberOverflow,
+    InvalidFloat,
+
+    DanglingOperator,
+    NonAssociativeOperator,
+
+    MissingMembers{names: Vec<&'static str>},
+}
+
+#[derive(Debug)]
+pub struct BytesParsingErrors<'a> {
+    errors: Vec<BytesParsingErrorKind<'a>>
+}
+
+impl<'a> BytesParsingErrors<'a> {
+    pub fn with_error_kind(kind: BytesParsingErrorKind<'a>) -> Self {
+        Self {
+            errors: vec![kind]
+        }
+    }
+}
+
+impl<'a> ParserError for BytesParsingErrors<'a> {
+    fn append(self, new: Self) -> Self {
+        let mut errors = self.errors;
+        errors.extend(new.errors);
+
+        Self {errors}
+    }
+}
+
+impl<'a> InputError<&'a [u8]> for BytesParsingErrors<'a> {
+    fn unexpected_end() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::UnexpectedEnd)
+    }
+
+    fn expected_end(found: &'a [u8]) -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::ExpectedEnd { found })
+    }
+}
+
+impl<'a> TagError<&'a [u8], &'a [u8]> for BytesParsingErrors<'a> {
+    fn unexpected_tag(expected: &'a [u8], found: &'a [u8]) -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::UnexpectedBytes { expected, found })
+    }
+}
+
+impl<'a> TagError<&'a str, &'a [u8]> for BytesParsingErrors<'a> {
+    fn unexpected_tag(expected: &'a str, found: &'a [u8]) -> Self {
+        <Self as TagError<&'a [u8], &'a [u8]>>::unexpected_tag(expected.as_bytes(), found)
+    }
+}
+
+impl<'a> LookaheadError<&'a [u8]> for BytesParsingErrors<'a> {
+    fn unexpected_match(found: &'a [u8]) -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::UnexpectedMatch { found })
+    }
+}
+
+impl<'a> NumberError for BytesParsingErrors<'a> {
+    fn expecting_digit() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::ExpectingDigit)
+    }
+
+    fn number_overflow() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::NumberOverflow)
+    }
+
+    fn invalid_float() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::InvalidFloat)
+    }
+}
+
+impl<'a> ExprError for BytesParsingErrors<'a> {
+    fn dangling_operator() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::DanglingOperator)
+    }
+
+    fn non_associative_operator() -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::NonAssociativeOperator)
+    }
+}
+
+impl<'a> PermutationError for BytesParsingErrors<'a> {
+    fn missing_members(names: Vec<&'static str>) -> Self {
+        Self::with_error_kind(BytesParsingErrorKind::MissingMembers { names })
+    }
+}
+
+#[derive(Debug)]
+pub enum TokenParsingErrorKind<'a, T> {
+    UnexpectedEnd,
+    UnexpectedToken{found: &'a T, index: usize},
+    UnexpectedMatch{found: &'a [T]},
+    ExpectedEnd{found: &'a [T]},
+
+    DanglingOperator,
+    NonAssociativeOperator,
+
+    MissingMembers{names: Vec<&'static str>},
+}
+
+#[derive(Debug)]
+pub struct TokenParsingErrors<'a, T> {
+    errors: Vec<TokenParsingErrorKind<'a, T>>
+}
+
+impl<'a, T> TokenParsingErrors<'a, T> {
+    pub fn with_error_kind(kind: TokenParsingErrorKind<'a, T>) -> Self {
+        Self {
+            errors: vec![kind]
+        }
+    }
+}
+
+impl<'a, T> ParserError for TokenParsingErrors<'a, T> {
+    fn append(self, new: Self) -> Self {
+        let mut errors = self.errors;
+        errors.extend(new.errors);
+
+        Self {errors}
+    }
+}
+
+impl<'a, T> InputError<&'a [T]> for TokenParsingErrors<'a, T> {
+    fn unexpected_end() -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::UnexpectedEnd)
+    }
+
+    fn expected_end(found: &'a [T]) -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::ExpectedEnd { found })
+    }
+}
+
+impl<'a, T> LookaheadError<&'a [T]> for TokenParsingErrors<'a, T> {
+    fn unexpected_match(found: &'a [T]) -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::UnexpectedMatch { found })
+    }
+}
+
+impl<'a, T> ExprError for TokenParsingErrors<'a, T> {
+    fn dangling_operator() -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::DanglingOperator)
+    }
+
+    fn non_associative_operator() -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::NonAssociativeOperator)
+    }
+}
+
+impl<'a, T> PermutationError for TokenParsingErrors<'a, T> {
+    fn missing_members(names: Vec<&'static str>) -> Self {
+        Self::with_error_kind(TokenParsingErrorKind::MissingMembers { names })
+    }
+}
+
+#[derive(Debug)]
+pub enum ReaderParsingErrorKind {
+    UnexpectedEnd,
+    UnexpectedBytes{expected: Vec<u8>, found: Vec<u8>},
+    UnexpectedMatch{found: Vec<u8>},
+    ExpectedEnd{found: Vec<u8>},
+    DiscardedInput,
+    InvalidUtf8,
+    Io{kind: io::ErrorKind, message: String},
+
+    ExpectingDigit,
+    NumberOverflow,
+    InvalidFloat,
+
+    DanglingOperator,
+    NonAssociativeOperator,
+
+    MissingMembers{names: Vec<&'static str>},
+}
+
+#[derive(Debug)]
+pub struct ReaderParsingErrors {
+    errors: Vec<ReaderParsingErrorKind>
+}
+
+impl ReaderParsingErrors {
+    pub fn with_error_kind(kind: ReaderParsingErrorKind) -> Self {
+        Self {
+            errors: vec![kind]
+        }
+    }
+}
+
+impl ParserError for ReaderParsingErrors {
+    fn append(self, new: Self) -> Self {
+        let mut errors = self.errors;
+        errors.extend(new.errors);
+
+        Self {errors}
+    }
+}
+
+impl<R: Read> InputError<ReaderInput<R>> for ReaderParsingErrors {
+    fn unexpected_end() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::UnexpectedEnd)
+    }
+
+    // Only the next byte is kept, to avoid reading the rest of the stream
+    fn expected_end(found: ReaderInput<R>) -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::ExpectedEnd {
+            found: found.iter_elements().take(1).collect()
+        })
+    }
+
+    fn discarded_input() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::DiscardedInput)
+    }
+
+    fn read_error(input: &ReaderInput<R>) -> Option<Self> {
+        input.io_error()
+            .map(|(kind, message)| Self::with_error_kind(ReaderParsingErrorKind::Io { kind, message }))
+    }
+}
+
+impl<R: Read> TagError<&[u8], ReaderInput<R>> for ReaderParsingErrors {
+    fn unexpected_tag(expected: &[u8], found: ReaderInput<R>) -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::UnexpectedBytes {
+            expected: expected.to_vec(),
+            found: found.iter_elements().take(expected.len()).collect()
+        })
+    }
+}
+
+impl<R: Read> TagError<&str, ReaderInput<R>> for ReaderParsingErrors {
+    fn unexpected_tag(expected: &str, found: ReaderInput<R>) -> Self {
+        Self::unexpected_tag(expected.as_bytes(), found)
+    }
+}
+
+impl<R: Read> LookaheadError<ReaderInput<R>> for ReaderParsingErrors {
+    fn unexpected_match(found: ReaderInput<R>) -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::UnexpectedMatch {
+            found: found.iter_elements().collect()
+        })
+    }
+}
+
+impl NumberError for ReaderParsingErrors {
+    fn expecting_digit() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::ExpectingDigit)
+    }
+
+    fn number_overflow() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::NumberOverflow)
+    }
+
+    fn invalid_float() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::InvalidFloat)
+    }
+}
+
+impl ExprError for ReaderParsingErrors {
+    fn dangling_operator() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::DanglingOperator)
+    }
+
+    fn non_associative_operator() -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::NonAssociativeOperator)
+    }
+}
+
+impl PermutationError for ReaderParsingErrors {
+    fn missing_members(names: Vec<&'static str>) -> Self {
+        Self::with_error_kind(ReaderParsingErrorKind::MissingMembers { names })
+    }
+}
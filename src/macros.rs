@@ -27,16 +27,19 @@ macro_rules! integers {
 }
 
 macro_rules! parseable_integers {
-    ($($t:ty)+, $str_parser:expr) => {$(
+    ($($t:ty)+, $parser:ident) => {$(
         impl<'a> crate::traits::ParseableInteger<'a> for $t {
-            fn str_parser(radix: u32) -> 
+            fn parser<I>(radix: u32) -> 
             impl crate::traits::Parser<
-                Input = &'a str,
+                Input = I,
                 Output = Self::Inner,
-                Error = crate::errors::StrParsingErrors<'a>
+                Error = I::Error
             > 
+            where
+                I: crate::traits::Input,
+                I::Error: crate::traits::NumberError
             {
-                $str_parser(radix)
+                crate::parsers::$parser::<I, Self>(radix)
             }
         }
     )+};
@@ -48,7 +51,7 @@ macro_rules! unsigned_integers {
         crate::macros::mark! {$($t)+, crate::traits::Unsigned}
         crate::macros::parseable_integers! {
             $($t)+,
-            crate::parsers::uint_parser::<Self>
+            uint_parser
         }
     };
 }
@@ -59,7 +62,7 @@ macro_rules! signed_integers {
         crate::macros::mark! {$($t)+, crate::traits::Signed}
         crate::macros::parseable_integers! {
             $($t)+,
-            crate::parsers::int_parser::<Self>
+            int_parser
         }
     };
 }
@@ -76,6 +79,182 @@ macro_rules! floats {
     )+};
 }
 
+macro_rules! tuple_parser {
+    ($first:ident $($p:ident)+) => {
+        impl<$first, $($p),+> crate::traits::Parser for ($first, $($p),+)
+        where
+            $first: crate::traits::Parser,
+            $(
+                $p: crate::traits::Parser<Input = $first::Input>,
+                $p::Error: Into<$first::Error>,
+            )+
+        {
+            type Input = $first::Input;
+            type Output = ($first::Output, $($p::Output),+);
+            type Error = $first::Error;
+
+            #[allow(non_snake_case)]
+            fn parse(&self, input: crate::parsers::ParserInput<Self::Input>) -> 
+            crate::parsers::ParserResult<Self::Input, Self::Output, Self::Error> 
+            {
+                let ($first, $($p),+) = self;
+
+                let (rest, $first) = $first.parse(input)?;
+                $(
+                    let (rest, $p) = $p.parse(rest)
+                        .map_err(|(input, error)| (input, error.into()))?;
+                )+
+
+                Ok((rest, ($first, $($p),+)))
+            }
+        }
+    };
+}
+
+macro_rules! alt_parser {
+    ($first:ident $($p:ident)+) => {
+        impl<$first, $($p),+> crate::traits::Parser for crate::combinators::Alt<($first, $($p),+)>
+        where
+            $first: crate::traits::Parser,
+            $first::Input: Clone,
+            $(
+                $p: crate::traits::Parser<Input = $first::Input, Output = $first::Output>,
+                $p::Error: Into<$first::Error>,
+            )+
+        {
+            type Input = $first::Input;
+            type Output = $first::Output;
+            type Error = $first::Error;
+
+            #[allow(non_snake_case)]
+            fn parse(&self, input: crate::parsers::ParserInput<Self::Input>) -> 
+            crate::parsers::ParserResult<Self::Input, Self::Output, Self::Error> 
+            {
+                let ($first, $($p),+) = &self.0;
+
+                let error = match $first.parse(input.clone()) {
+                    Err((_, error)) if error.is_recoverable() => error,
+                    result => return result,
+                };
+                $(
+                    let error = match $p.parse(input.clone()) {
+                        Err((_, new_error)) if new_error.is_recoverable() => 
+                            crate::traits::ParserError::append(error, new_error.into()),
+                        Err((rest, new_error)) => return Err((rest, new_error.into())),
+                        Ok(result) => return Ok(result),
+                    };
+                )+
+
+                Err((input, error))
+            }
+        }
+    };
+}
+
+macro_rules! permutation_parser {
+    ($first:ident $($p:ident)+) => {
+        impl<$first, $($p),+> crate::traits::Parser for crate::combinators::Permutation<($first, $($p),+)>
+        where
+            $first: crate::traits::PermutationMember,
+            <$first::Parser as crate::traits::Parser>::Input: Clone,
+            <$first::Parser as crate::traits::Parser>::Error: crate::traits::PermutationError,
+            $(
+                $p: crate::traits::PermutationMember,
+                $p::Parser: crate::traits::Parser<Input = <$first::Parser as crate::traits::Parser>::Input>,
+                <$p::Parser as crate::traits::Parser>::Error: Into<<$first::Parser as crate::traits::Parser>::Error>,
+            )+
+        {
+            type Input = <$first::Parser as crate::traits::Parser>::Input;
+            type Output = ($first::Output, $($p::Output),+);
+            type Error = <$first::Parser as crate::traits::Parser>::Error;
+
+            #[allow(non_snake_case)]
+            fn parse(&self, input: crate::parsers::ParserInput<Self::Input>) -> 
+            crate::parsers::ParserResult<Self::Input, Self::Output, Self::Error> 
+            {
+                let ($first, $($p),+) = &self.0;
+                let mut $first = ($first, None);
+                $(let mut $p = ($p, None);)+
+
+                let mut current_input = input;
+                let mut error: Option<crate::errors::ParsingError<Self::Error>> = None;
+                'members: loop {
+                    if $first.1.is_none() {
+                        match crate::traits::Parser::parse($first.0.parser(), current_input.clone()) {
+                            Ok((rest, output)) => {
+                                $first.1 = Some(output);
+                                current_input = rest;
+                                error = None;
+                                continue 'members;
+                            }
+                            Err((rest, new_error)) if !new_error.is_recoverable() => return Err((rest, new_error)),
+                            Err((_, new_error)) => error = Some(match error {
+                                Some(error) => crate::traits::ParserError::append(error, new_error),
+                                None => new_error
+                            })
+                        }
+                    }
+                    $(
+                        if $p.1.is_none() {
+                            match crate::traits::Parser::parse($p.0.parser(), current_input.clone()) {
+                                Ok((rest, output)) => {
+                                    $p.1 = Some(output);
+                                    current_input = rest;
+                                    error = None;
+                                    continue 'members;
+                                }
+                                Err((rest, new_error)) if !new_error.is_recoverable() => return Err((rest, new_error.into())),
+                                Err((_, new_error)) => error = Some(match error {
+                                    Some(error) => crate::traits::ParserError::append(error, new_error.into()),
+                                    None => new_error.into()
+                                })
+                            }
+                        }
+                    )+
+                    break;
+                }
+
+                let mut missing = Vec::new();
+                if $first.0.is_required() && $first.1.is_none() {
+                    missing.push($first.0.name());
+                }
+                $(
+                    if $p.0.is_required() && $p.1.is_none() {
+                        missing.push($p.0.name());
+                    }
+                )+
+
+                if !missing.is_empty() {
+                    let missing_error = crate::errors::ParsingError {
+                        error: <Self::Error as crate::traits::PermutationError>::missing_members(missing).into(),
+                        position: current_input.position
+                    };
+                    let error = match error {
+                        Some(error) => crate::traits::ParserError::append(missing_error, error),
+                        None => missing_error
+                    };
+                    return Err((current_input, error));
+                }
+
+                Ok((
+                    current_input,
+                    (
+                        crate::traits::PermutationMember::finish($first.0, $first.1),
+                        $(crate::traits::PermutationMember::finish($p.0, $p.1)),+
+                    )
+                ))
+            }
+        }
+    };
+}
+
+macro_rules! tuple_parsers {
+    ($($($p:ident)+),+) => {$(
+        crate::macros::tuple_parser! {$($p)+}
+        crate::macros::alt_parser! {$($p)+}
+        crate::macros::permutation_parser! {$($p)+}
+    )+};
+}
 
 pub(crate) use parseable_integers;
 pub(crate) use mark;
@@ -83,4 +262,8 @@ pub(crate) use numbers;
 pub(crate) use signed_integers;
 pub(crate) use integers;
 pub(crate) use unsigned_integers;
-pub(crate) use floats;
\ No newline at end of file
+pub(crate) use floats;
+pub(crate) use alt_parser;
+pub(crate) use permutation_parser;
+pub(crate) use tuple_parser;
+pub(crate) use tuple_parsers;
\ No newline at end of file
@@ -5,10 +5,10 @@ fn main() {
         '['
         .then_parse(f32::str_parser())
         .then_consume(&']')
-        .parse("[ff]".into())
+        .parse_str("[1.5]")
     ;
     match result {
-        Ok((rest, output)) => println!("rest \"{rest}\", output={output}"),
-        Err((_, error)) => println!("error {error:?}"),
+        Ok(output) => println!("output={output}"),
+        Err(report) => println!("error {report}"),
     }
 }
\ No newline at end of file
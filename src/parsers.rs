@@ -1,35 +1,173 @@
 mod str;
+pub mod bytes;
+pub mod token;
+pub mod reader;
+pub mod expr;
 
-use std::fmt::{Debug, Display};
+use std::{fmt::{Debug, Display}, ops::Range};
 
 pub use str::*;
+pub use crate::combinators::{
+    alt, choice, delimited, from_fn, lazy, member, not, optional_member, permutation, preceded, recursive, terminated
+};
 
-use crate::{errors::ParsingError, traits::Parser};
+use crate::{errors::{ParsingError, ParsingErrorKind}, traits::{AsChar, Input, InputError, Parser}};
 
 type GenericResult<I, O, E> = Result<(I, O), (I, E)>;
 pub type ParserResult<I, O, E> = GenericResult<ParserInput<I>, O, ParsingError<E>>;
+pub type BoxedParser<'p, I, O, E> = Box<dyn Parser<Input = I, Output = O, Error = E> + 'p>;
 
 
+/// Location of the parser inside the original input.
+///
+/// `offset` counts bytes, so it can always be used to slice the original `&str`.
+/// `char_offset` counts the items (chars for text input) consumed so far, and
+/// `column` counts items since the start of the current line. Both `\n` and
+/// `\r\n` end a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub offset: usize,
+    pub char_offset: usize,
+    pub line: usize,
+    pub column: usize
+}
+
+impl Position {
+    pub(crate) fn advance<C: AsChar>(&mut self, item: C) {
+        self.offset += item.item_len();
+        self.char_offset += 1;
+        if item.as_char() == '\n' {
+            self.line += 1;
+            self.column = 1;
+        }
+        else {
+            self.column += 1;
+        }
+    }
+
+    pub(crate) fn skip(&mut self, count: usize) {
+        self.offset += count;
+        self.char_offset += count;
+        self.column += count;
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            char_offset: 0,
+            line: 1,
+            column: 1
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Range<usize>
+}
+
 pub struct ParserInput<T> {
     pub(crate) data: T,
-    pub(crate) index: usize
+    pub(crate) position: Position,
+    pub(crate) partial: bool
+}
+
+impl<T> ParserInput<T> {
+    /// Input that may be followed by more data: parsers running out of it
+    /// fail with `ParsingErrorKind::Incomplete` instead of a regular error.
+    pub fn partial(data: T) -> Self {
+        Self {
+            data,
+            position: Position::default(),
+            partial: true
+        }
+    }
+
+    pub fn is_partial(&self) -> bool {
+        self.partial
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl<T> ParserInput<T> {
+    // Checked before reporting the end of the input, which may come from a failed read
+    pub(crate) fn check_read_error<E: InputError<T>>(self) -> Result<Self, (Self, ParsingError<E>)> {
+        let Some(error) = E::read_error(&self.data) else {
+            return Ok(self);
+        };
+
+        let position = self.position;
+        Err((
+            self,
+            ParsingError {
+                error: ParsingErrorKind::Custom(error).cut(),
+                position
+            }
+        ))
+    }
+}
+
+impl<T: Input> ParserInput<T> {
+    // Reading an input discarded by a commit means that the parser backtracked past it
+    pub(crate) fn check_discarded(self) -> Result<Self, (Self, ParsingError<T::Error>)> {
+        if !self.data.is_discarded() {
+            return Ok(self);
+        }
+
+        let position = self.position;
+        Err((
+            self,
+            ParsingError {
+                error: ParsingErrorKind::Custom(T::Error::discarded_input()).cut(),
+                position
+            }
+        ))
+    }
+
+    pub(crate) fn take_split(&self, count: usize) -> (T, Self) {
+        let (taken, rest) = self.data.take_split(count);
+        let mut position = self.position;
+        taken.iter_elements().for_each(|item| position.advance(item));
+
+        (taken, ParserInput {
+            data: rest,
+            position,
+            partial: self.partial
+        })
+    }
 }
 
 impl<T: Clone> Clone for ParserInput<T> {
     fn clone(&self) -> Self {
-        Self { data: self.data.clone(), index: self.index.clone() }
+        Self { data: self.data.clone(), position: self.position, partial: self.partial }
     }
 }
 
 impl<T: Debug> Debug for ParserInput<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ParserInput").field("data", &self.data).field("index", &self.index).finish()
+        f.debug_struct("ParserInput")
+            .field("data", &self.data)
+            .field("position", &self.position)
+            .field("partial", &self.partial)
+            .finish()
     }
 }
 
 impl<T: Display> Display for ParserInput<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.data)
+        write!(f, "{} at {}", self.data, self.position)
     }
 }
 
@@ -37,25 +175,41 @@ impl<T> From<T> for ParserInput<T> {
     fn from(value: T) -> Self {
         Self {
             data: value,
-            index: 0
+            position: Position::default(),
+            partial: false
         }
     }
 }
 
 pub(crate) fn parse_many<P: Parser>(parser: &P, input: ParserInput<P::Input>) 
-    -> (ParserInput<P::Input>, Vec<P::Output>)
+    -> ParserResult<P::Input, Vec<P::Output>, P::Error>
 {
-    let mut result = Vec::new();
+    parse_fold(parser, input, Vec::new(), |mut result, output| {
+        result.push(output);
+        result
+    })
+}
+
+pub(crate) fn parse_fold<P, R, F>(parser: &P, input: ParserInput<P::Input>, init: R, folder: F) 
+    -> ParserResult<P::Input, R, P::Error>
+where
+    P: Parser,
+    F: Fn(R, P::Output) -> R
+{
+    let mut result = init;
     let mut current_input = input;
     loop {
         match parser.parse(current_input) {
             Ok((rest, output)) => {
-                result.push(output);
+                result = folder(result, output);
 
                 current_input = rest;
             }
+            Err((input, error)) if !error.is_recoverable() => {
+                return Err((input, error))
+            }
             Err((input, _)) => {
-                return (input, result)
+                return Ok((input, result))
             }
         }
     }
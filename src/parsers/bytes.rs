@@ -0,0 +1,242 @@
+use std::marker::PhantomData;
+
+use crate::{errors::{BytesParsingErrorKind, BytesParsingErrors, Needed, ParsingError, ParsingErrorKind}, parsers::Parser, traits::{AsChar, Compare, CompareResult, Input, InputEnd, InputError, InputSlice}};
+
+use super::{tag, ParserInput, ParserResult};
+
+
+pub fn any_byte<I: Input<Item = u8>>() ->
+impl Parser<
+    Input = I,
+    Output = u8,
+    Error = I::Error
+>
+{
+    AnyByte {
+        _marker: PhantomData
+    }
+}
+
+pub fn take<I: Input>(count: usize) ->
+impl Parser<
+    Input = I,
+    Output = I,
+    Error = I::Error
+>
+{
+    Take {
+        count,
+        _marker: PhantomData
+    }
+}
+
+pub(crate) fn byte_parser<I: Input<Item = u8>>(expected: u8) ->
+impl Parser<
+    Input = I,
+    Output = u8,
+    Error = I::Error
+>
+{
+    any_byte()
+    .verify(move |b| *b == expected)
+}
+
+struct AnyByte<I> {
+    _marker: PhantomData<I>
+}
+
+impl<I: Input<Item = u8>> Parser for AnyByte<I> {
+    type Input = I;
+    type Output = u8;
+    type Error = I::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let input = input.check_discarded()?;
+        let position = input.position;
+        match input.data.iter_elements().next() {
+            Some(byte) => {
+                let (_, rest) = input.take_split(1);
+                Ok((rest, byte))
+            }
+            None => {
+                let input = input.check_read_error::<I::Error>()?;
+                let error = if input.partial {
+                    ParsingErrorKind::Incomplete { needed: Needed::Size(1) }
+                }
+                else {
+                    I::Error::unexpected_end().into()
+                };
+
+                Err((
+                    input,
+                    ParsingError {
+                        error,
+                        position
+                    }
+                ))
+            }
+        }
+    }
+}
+
+struct Take<I> {
+    count: usize,
+    _marker: PhantomData<I>
+}
+
+impl<I: Input> Parser for Take<I> {
+    type Input = I;
+    type Output = I;
+    type Error = I::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let input = input.check_discarded()?;
+        let position = input.position;
+        let (items, len) = input.data.iter_elements()
+            .take(self.count)
+            .fold((0, 0), |(items, len), item| (items + 1, len + item.item_len()));
+
+        if items == self.count {
+            let (taken, rest) = input.take_split(len);
+            Ok((rest, taken))
+        }
+        else {
+            let input = input.check_read_error::<I::Error>()?;
+            let error = if input.partial {
+                ParsingErrorKind::Incomplete {
+                    needed: Needed::Size(self.count - items)
+                }
+            }
+            else {
+                I::Error::unexpected_end().into()
+            };
+
+            Err((
+                input,
+                ParsingError {
+                    error,
+                    position
+                }
+            ))
+        }
+    }
+}
+
+impl<'a> Input for &'a [u8] {
+    type Item = u8;
+    type Iter = std::iter::Copied<std::slice::Iter<'a, u8>>;
+    type Error = BytesParsingErrors<'a>;
+
+    fn iter_elements(&self) -> Self::Iter {
+        self.iter().copied()
+    }
+
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        self.split_at(count)
+    }
+
+    fn offset(&self, rest: &Self) -> usize {
+        rest.as_ptr() as usize - self.as_ptr() as usize
+    }
+
+    fn with_str<F, R>(&self, f: F) -> R
+    where F: FnOnce(&str) -> R
+    {
+        match std::str::from_utf8(self) {
+            Ok(s) => f(s),
+            Err(_) => f(&String::from_utf8_lossy(self))
+        }
+    }
+
+    fn next_char(&self) -> Option<Result<(char, usize), Self::Error>> {
+        decode_utf8(self.iter_elements())
+            .map(|decoded| decoded.ok_or_else(|| BytesParsingErrors::with_error_kind(BytesParsingErrorKind::InvalidUtf8)))
+    }
+}
+
+// None when the bytes end in the middle of the char, and Some(None) when they are not valid UTF-8
+pub(crate) fn decode_utf8(mut bytes: impl Iterator<Item = u8>) -> Option<Option<(char, usize)>> {
+    let first = bytes.next()?;
+    let len = match first {
+        0x00..=0x7f => 1,
+        0xc2..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf4 => 4,
+        _ => return Some(None)
+    };
+
+    let mut buffer = [first, 0, 0, 0];
+    for byte in &mut buffer[1..len] {
+        *byte = bytes.next()?;
+    }
+
+    Some(
+        std::str::from_utf8(&buffer[..len])
+            .ok()
+            .and_then(|s| s.chars().next())
+            .map(|c| (c, len))
+    )
+}
+
+// Covers token slices as well
+impl<T> InputEnd for &[T] {
+    fn is_at_end(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> InputSlice for &[T] {
+    fn consumed(&self, rest: &Self) -> Self {
+        &self[..self.len() - rest.len()]
+    }
+}
+
+impl<'b> Compare<&'b [u8]> for &[u8] {
+    fn compare(&self, tag: &'b [u8]) -> CompareResult {
+        if self.starts_with(tag) {
+            CompareResult::Ok(tag.len())
+        }
+        else if tag.starts_with(self) {
+            CompareResult::Incomplete(tag.len() - self.len())
+        }
+        else {
+            CompareResult::Error
+        }
+    }
+}
+
+impl<'b> Compare<&'b str> for &[u8] {
+    fn compare(&self, tag: &'b str) -> CompareResult {
+        self.compare(tag.as_bytes())
+    }
+}
+
+impl<'a> Parser for &'a u8 {
+    type Input = &'a [u8];
+    type Output = u8;
+    type Error = BytesParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        byte_parser(**self).parse(input)
+    }
+}
+
+impl<'a> Parser for &'a [u8] {
+    type Input = &'a [u8];
+    type Output = &'a [u8];
+    type Error = BytesParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        tag(*self).parse(input)
+    }
+}
+
+impl<'a, const N: usize> Parser for &'a [u8; N] {
+    type Input = &'a [u8];
+    type Output = &'a [u8];
+    type Error = BytesParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        tag(self.as_slice()).parse(input)
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{errors::{ParsingError, ParsingErrorKind}, parsers::Parser, traits::{ExprError, ParserError}};
+
+use super::{ParserInput, ParserResult, Position};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+    None,
+}
+
+pub fn expr<'p, A>(atom: A) -> ExprParser<'p, A>
+where
+    A: Parser,
+    A::Input: Clone,
+    A::Error: ExprError
+{
+    ExprParser {
+        atom,
+        prefix: Vec::new(),
+        infix: Vec::new(),
+        postfix: Vec::new()
+    }
+}
+
+type PrefixBuilder<'b, O> = Box<dyn FnOnce(O) -> O + 'b>;
+type InfixBuilder<'b, O> = Box<dyn FnOnce(O, O) -> O + 'b>;
+
+type FoundPrefix<'b, O> = Option<(u32, PrefixBuilder<'b, O>)>;
+type FoundInfix<'b, O> = Option<(u32, Assoc, InfixBuilder<'b, O>)>;
+
+trait PrefixOperator<I, O, E> {
+    fn parse_operator(&self, input: ParserInput<I>) -> ParserResult<I, PrefixBuilder<'_, O>, E>;
+}
+
+trait InfixOperator<I, O, E> {
+    fn parse_operator(&self, input: ParserInput<I>) -> ParserResult<I, InfixBuilder<'_, O>, E>;
+}
+
+struct Operator<P, F> {
+    p: P,
+    build: F
+}
+
+impl<P, F, O, E> PrefixOperator<P::Input, O, E> for Operator<P, F>
+where
+    P: Parser,
+    P::Error: Into<E>,
+    F: Fn(P::Output, O) -> O,
+    E: ParserError
+{
+    fn parse_operator(&self, input: ParserInput<P::Input>) -> ParserResult<P::Input, PrefixBuilder<'_, O>, E> {
+        match self.p.parse(input) {
+            Ok((rest, op)) => Ok((rest, Box::new(move |operand| (self.build)(op, operand)))),
+            Err((input, error)) => Err((input, error.into()))
+        }
+    }
+}
+
+impl<P, F, O, E> InfixOperator<P::Input, O, E> for Operator<P, F>
+where
+    P: Parser,
+    P::Error: Into<E>,
+    F: Fn(O, P::Output, O) -> O,
+    E: ParserError
+{
+    fn parse_operator(&self, input: ParserInput<P::Input>) -> ParserResult<P::Input, InfixBuilder<'_, O>, E> {
+        match self.p.parse(input) {
+            Ok((rest, op)) => Ok((rest, Box::new(move |lhs, rhs| (self.build)(lhs, op, rhs)))),
+            Err((input, error)) => Err((input, error.into()))
+        }
+    }
+}
+
+// Postfix operators are applied like prefix ones, to the operand on their left
+struct PostfixOperator<P, F> {
+    p: P,
+    build: F
+}
+
+impl<P, F, O, E> PrefixOperator<P::Input, O, E> for PostfixOperator<P, F>
+where
+    P: Parser,
+    P::Error: Into<E>,
+    F: Fn(O, P::Output) -> O,
+    E: ParserError
+{
+    fn parse_operator(&self, input: ParserInput<P::Input>) -> ParserResult<P::Input, PrefixBuilder<'_, O>, E> {
+        match self.p.parse(input) {
+            Ok((rest, op)) => Ok((rest, Box::new(move |operand| (self.build)(operand, op)))),
+            Err((input, error)) => Err((input, error.into()))
+        }
+    }
+}
+
+type Prefix<'p, A> = Box<dyn PrefixOperator<
+    <A as Parser>::Input,
+    <A as Parser>::Output,
+    <A as Parser>::Error
+> + 'p>;
+
+type Infix<'p, A> = Box<dyn InfixOperator<
+    <A as Parser>::Input,
+    <A as Parser>::Output,
+    <A as Parser>::Error
+> + 'p>;
+
+// Binding powers: higher binds tighter.
+// An operator of binding power `bp` binds with `2 * bp` on its left side
+// and `2 * bp + 1` on its right side, swapped for right associativity.
+pub struct ExprParser<'p, A: Parser> {
+    atom: A,
+    prefix: Vec<(u32, Prefix<'p, A>)>,
+    infix: Vec<(u32, Assoc, Infix<'p, A>)>,
+    postfix: Vec<(u32, Prefix<'p, A>)>
+}
+
+impl<'p, A> ExprParser<'p, A>
+where
+    A: Parser + 'p,
+    A::Input: Clone,
+    A::Error: ExprError
+{
+    pub fn prefix<P, F>(mut self, op: P, binding_power: u32, build: F) -> Self
+    where
+        P: Parser<Input = A::Input> + 'p,
+        P::Error: Into<A::Error>,
+        F: Fn(P::Output, A::Output) -> A::Output + 'p
+    {
+        self.prefix.push((binding_power, Box::new(Operator { p: op, build })));
+        self
+    }
+
+    pub fn infix<P, F>(mut self, op: P, assoc: Assoc, binding_power: u32, build: F) -> Self
+    where
+        P: Parser<Input = A::Input> + 'p,
+        P::Error: Into<A::Error>,
+        F: Fn(A::Output, P::Output, A::Output) -> A::Output + 'p
+    {
+        self.infix.push((binding_power, assoc, Box::new(Operator { p: op, build })));
+        self
+    }
+
+    pub fn postfix<P, F>(mut self, op: P, binding_power: u32, build: F) -> Self
+    where
+        P: Parser<Input = A::Input> + 'p,
+        P::Error: Into<A::Error>,
+        F: Fn(A::Output, P::Output) -> A::Output + 'p
+    {
+        self.postfix.push((binding_power, Box::new(PostfixOperator { p: op, build })));
+        self
+    }
+
+    fn parse_expr(&self, input: ParserInput<A::Input>, min_bp: u32)
+        -> ParserResult<A::Input, A::Output, A::Error>
+    {
+        let (mut current_input, mut lhs) = match self.find_prefix(&self.prefix, &input)? {
+            (rest, Some((binding_power, build))) => {
+                let (rest, operand) = self.parse_operand(rest, 2 * binding_power + 1, input.position)?;
+                (rest, build(operand))
+            }
+            (_, None) => self.atom.parse(input)?
+        };
+
+        let mut non_associative = None;
+        loop {
+            if let (rest, Some((binding_power, build))) = self.find_prefix(&self.postfix, &current_input)? {
+                if 2 * binding_power < min_bp {
+                    break;
+                }
+
+                lhs = build(lhs);
+                current_input = rest;
+                continue;
+            }
+
+            let (rest, Some((binding_power, assoc, build))) = self.find_infix(&current_input)? else {
+                break;
+            };
+
+            let (left_bp, right_bp) = match assoc {
+                Assoc::Right => (2 * binding_power + 1, 2 * binding_power),
+                _ => (2 * binding_power, 2 * binding_power + 1)
+            };
+            if left_bp < min_bp {
+                break;
+            }
+
+            let position = current_input.position;
+            if assoc == Assoc::None && non_associative == Some(binding_power) {
+                return Err((
+                    current_input,
+                    ParsingError {
+                        error: A::Error::non_associative_operator().into(),
+                        position
+                    }
+                ));
+            }
+
+            let (rest, rhs) = self.parse_operand(rest, right_bp, position)?;
+            lhs = build(lhs, rhs);
+            current_input = rest;
+            non_associative = (assoc == Assoc::None).then_some(binding_power);
+        }
+
+        Ok((current_input, lhs))
+    }
+
+    fn find_prefix<'o>(&self, operators: &'o [(u32, Prefix<'p, A>)], input: &ParserInput<A::Input>)
+        -> ParserResult<A::Input, FoundPrefix<'o, A::Output>, A::Error>
+    {
+        for (binding_power, operator) in operators {
+            match operator.parse_operator(input.clone()) {
+                Ok((rest, build)) => return Ok((rest, Some((*binding_power, build)))),
+                Err((input, error)) if !error.is_recoverable() => return Err((input, error)),
+                Err(_) => {}
+            }
+        }
+
+        Ok((input.clone(), None))
+    }
+
+    fn find_infix(&self, input: &ParserInput<A::Input>)
+        -> ParserResult<A::Input, FoundInfix<'_, A::Output>, A::Error>
+    {
+        for (binding_power, assoc, operator) in &self.infix {
+            match operator.parse_operator(input.clone()) {
+                Ok((rest, build)) => return Ok((rest, Some((*binding_power, *assoc, build)))),
+                Err((input, error)) if !error.is_recoverable() => return Err((input, error)),
+                Err(_) => {}
+            }
+        }
+
+        Ok((input.clone(), None))
+    }
+
+    // An operand that fails right after its operator makes the operator dangling
+    fn parse_operand(&self, input: ParserInput<A::Input>, min_bp: u32, operator_position: Position)
+        -> ParserResult<A::Input, A::Output, A::Error>
+    {
+        let start = input.position;
+        self.parse_expr(input, min_bp)
+        .map_err(|(input, error)| {
+            if !error.is_recoverable() || error.position != start {
+                return (input, error);
+            }
+
+            (
+                input,
+                ParsingError {
+                    error: ParsingErrorKind::Custom(A::Error::dangling_operator()).append(error.error),
+                    position: operator_position
+                }
+            )
+        })
+    }
+}
+
+impl<'p, A> Parser for ExprParser<'p, A>
+where
+    A: Parser + 'p,
+    A::Input: Clone,
+    A::Error: ExprError
+{
+    type Input = A::Input;
+    type Output = A::Output;
+    type Error = A::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        self.parse_expr(input, 0)
+    }
+}
@@ -0,0 +1,245 @@
+use std::{cell::RefCell, fmt::Debug, io::{self, Read}, rc::Rc};
+
+use crate::{errors::{ReaderParsingErrorKind, ReaderParsingErrors}, traits::{Compare, CompareResult, Input, InputEnd, InputSlice}};
+
+use super::bytes::decode_utf8;
+
+const CHUNK_SIZE: usize = 8 * 1024;
+
+// Without a reader, the buffer holds bytes taken out of another one
+struct ReaderBuffer<R> {
+    reader: Option<R>,
+    data: Vec<u8>,
+    start: usize,
+    eof: bool,
+    error: Option<io::Error>
+}
+
+impl<R: Read> ReaderBuffer<R> {
+    fn fill(&mut self) {
+        let Some(reader) = self.reader.as_mut() else {
+            self.eof = true;
+            return;
+        };
+
+        let mut chunk = [0; CHUNK_SIZE];
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => {
+                    self.eof = true;
+                    return;
+                }
+                Ok(read) => {
+                    self.data.extend_from_slice(&chunk[..read]);
+                    return;
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    self.error = Some(error);
+                    self.eof = true;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn byte_at(&mut self, offset: usize) -> Option<u8> {
+        if offset < self.start {
+            return None;
+        }
+
+        while offset >= self.start + self.data.len() && !self.eof {
+            self.fill();
+        }
+
+        self.data.get(offset - self.start).copied()
+    }
+
+    fn discard_before(&mut self, offset: usize) {
+        if offset > self.start {
+            let count = (offset - self.start).min(self.data.len());
+            self.data.drain(..count);
+            self.start += count;
+        }
+    }
+}
+
+// Every clone shares the same buffer, which keeps everything read since the
+// last commit so that combinators can backtrack over it, while the parts taken
+// out of the input, like the output of tag or recognize, own their bytes.
+// Parsing an input taken before the last commit fails with a cut error.
+pub struct ReaderInput<R> {
+    buffer: Rc<RefCell<ReaderBuffer<R>>>,
+    start: usize,
+    end: Option<usize>
+}
+
+impl<R: Read> ReaderInput<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            buffer: Rc::new(RefCell::new(ReaderBuffer {
+                reader: Some(reader),
+                data: Vec::new(),
+                start: 0,
+                eof: false,
+                error: None
+            })),
+            start: 0,
+            end: None
+        }
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.iter_elements().collect()
+    }
+
+    pub fn take_io_error(&self) -> Option<io::Error> {
+        self.buffer.borrow_mut().error.take()
+    }
+
+    // The error that ended the stream, unless this input stops before the end of it
+    pub(crate) fn io_error(&self) -> Option<(io::ErrorKind, String)> {
+        if self.end.is_some() {
+            return None;
+        }
+
+        self.buffer.borrow().error.as_ref().map(|error| (error.kind(), error.to_string()))
+    }
+}
+
+impl<R> Clone for ReaderInput<R> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            start: self.start,
+            end: self.end
+        }
+    }
+}
+
+impl<R> Debug for ReaderInput<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReaderInput").field("start", &self.start).field("end", &self.end).finish()
+    }
+}
+
+pub struct ReaderIter<R> {
+    buffer: Rc<RefCell<ReaderBuffer<R>>>,
+    offset: usize,
+    end: Option<usize>
+}
+
+impl<R: Read> Iterator for ReaderIter<R> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end.is_some_and(|end| self.offset >= end) {
+            return None;
+        }
+
+        let byte = self.buffer.borrow_mut().byte_at(self.offset)?;
+        self.offset += 1;
+        Some(byte)
+    }
+}
+
+impl<R: Read> Input for ReaderInput<R> {
+    type Item = u8;
+    type Iter = ReaderIter<R>;
+    type Error = ReaderParsingErrors;
+
+    fn iter_elements(&self) -> Self::Iter {
+        ReaderIter {
+            buffer: self.buffer.clone(),
+            offset: self.start,
+            end: self.end
+        }
+    }
+
+    // The taken bytes are copied out of the shared buffer, so that they stay
+    // readable after a later commit discards them
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        let middle = self.start + count;
+        let taken = ReaderIter {
+            buffer: self.buffer.clone(),
+            offset: self.start,
+            end: Some(middle)
+        }
+        .collect();
+
+        (
+            Self {
+                buffer: Rc::new(RefCell::new(ReaderBuffer {
+                    reader: None,
+                    data: taken,
+                    start: self.start,
+                    eof: true,
+                    error: None
+                })),
+                start: self.start,
+                end: Some(middle)
+            },
+            Self {
+                buffer: self.buffer.clone(),
+                start: middle,
+                end: self.end
+            }
+        )
+    }
+
+    fn offset(&self, rest: &Self) -> usize {
+        rest.start - self.start
+    }
+
+    fn with_str<F, T>(&self, f: F) -> T
+    where F: FnOnce(&str) -> T
+    {
+        f(&String::from_utf8_lossy(&self.to_vec()))
+    }
+
+    fn next_char(&self) -> Option<Result<(char, usize), Self::Error>> {
+        decode_utf8(self.iter_elements())
+            .map(|decoded| decoded.ok_or_else(|| ReaderParsingErrors::with_error_kind(ReaderParsingErrorKind::InvalidUtf8)))
+    }
+
+    fn commit(&self) {
+        self.buffer.borrow_mut().discard_before(self.start);
+    }
+
+    fn is_discarded(&self) -> bool {
+        self.start < self.buffer.borrow().start
+    }
+}
+
+impl<R: Read> InputEnd for ReaderInput<R> {
+    fn is_at_end(&self) -> bool {
+        !self.is_discarded() && self.iter_elements().next().is_none()
+    }
+}
+
+impl<R: Read> InputSlice for ReaderInput<R> {
+    fn consumed(&self, rest: &Self) -> Self {
+        self.take_split(self.offset(rest)).0
+    }
+}
+
+impl<R: Read> Compare<&[u8]> for ReaderInput<R> {
+    fn compare(&self, tag: &[u8]) -> CompareResult {
+        let mut elements = self.iter_elements();
+        for (index, expected) in tag.iter().enumerate() {
+            match elements.next() {
+                Some(byte) if byte == *expected => {}
+                Some(_) => return CompareResult::Error,
+                None => return CompareResult::Incomplete(tag.len() - index),
+            }
+        }
+
+        CompareResult::Ok(tag.len())
+    }
+}
+
+impl<R: Read> Compare<&str> for ReaderInput<R> {
+    fn compare(&self, tag: &str) -> CompareResult {
+        self.compare(tag.as_bytes())
+    }
+}
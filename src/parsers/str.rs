@@ -1,148 +1,175 @@
-use crate::{errors::{ParsingError, StrParsingErrorKind, StrParsingErrors}, parsers::Parser, traits::{Float, Integer, Signed, Unsigned}};
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+use crate::{errors::{Needed, ParsingError, ParsingErrorKind, StrParsingErrors}, parsers::Parser, traits::{Compare, CompareResult, Float, Input, InputEnd, InputError, InputSlice, Integer, NumberError, Signed, TagError, Unsigned}};
 
 use super::{ParserInput, ParserResult};
 
 
-pub fn any_char<'a>() ->
+pub fn any_char<I: Input>() ->
 impl Parser<
-    Input = &'a str,
+    Input = I,
     Output = char,
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
 {
     AnyChar {
-        _private: &()
+        _marker: PhantomData
     }
 }
 
 
-pub(crate) fn char_parser<'a>(expected: char) -> 
+pub fn eof<I: Input>() ->
 impl Parser<
-    Input = &'a str,
+    Input = I,
+    Output = (),
+    Error = I::Error
+>
+{
+    Eof {
+        _marker: PhantomData
+    }
+}
+
+pub fn commit<I: Input>() ->
+impl Parser<
+    Input = I,
+    Output = (),
+    Error = I::Error
+>
+{
+    Commit {
+        _marker: PhantomData
+    }
+}
+
+pub(crate) fn char_parser<I: Input>(expected: char) -> 
+impl Parser<
+    Input = I,
     Output = char,
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
 {
     any_char()
     .verify(move |c| *c == expected)
 }
 
-pub(crate) fn string_parser<'a>(expected: &'a str) -> 
+pub fn tag<I, T>(expected: T) -> 
 impl Parser<
-    Input = &'a str,
-    Output = &'a str,
-    Error = StrParsingErrors
+    Input = I,
+    Output = I,
+    Error = I::Error
 >
+where
+    I: Input + Compare<T>,
+    I::Error: TagError<T, I>,
+    T: Clone
 {
-    StringParser {
-        expected
+    Tag {
+        expected,
+        _marker: PhantomData
     }
 }
 
-fn number_str_parser<'a>(radix: u32, signed: bool) -> 
+fn digits_parser<I>(radix: u32) -> 
 impl Parser<
-    Input = &'a str,
-    Output = String,
-    Error = StrParsingErrors<'a>
+    Input = I,
+    Output = (),
+    Error = I::Error
 >
+where
+    I: Input,
+    I::Error: NumberError
 {
-    let sign_str = if signed && radix == 10 {"-"} else {""};
-
     any_char()
     .verify(move |c| c.is_digit(radix))
-    .many1()
-    .map( move |digits| 
-        sign_str.to_string() + &String::from_iter(digits)
-    )
+    .fold_many1((), |_, _| ())
     .map_err(|error| {
         match error {
-            crate::errors::ParsingErrorKind::VerifyError => 
-                StrParsingErrors::with_error_kind(
-                    StrParsingErrorKind::ExpectingDigit
-                ).into(),
-            crate::errors::ParsingErrorKind::Custom(e) => e,
+            ParsingErrorKind::Custom(e) => e,
+            _ => I::Error::expecting_digit(),
         }
     })
 
 }
 
-pub(crate) fn uint_parser<'a, N: Integer + Unsigned>(radix: u32) -> 
+pub(crate) fn uint_parser<I, N: Integer + Unsigned>(radix: u32) -> 
 impl Parser<
-    Input = &'a str,
+    Input = I,
     Output = N::Inner,
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
+where
+    I: Input,
+    I::Error: NumberError
 {
-    number_str_parser(radix,false)
-    .map_result(move |number| 
-        N::from_str(&number, radix)
-        .map_err(|_| StrParsingErrors::with_error_kind(
-            StrParsingErrorKind::NumberOverflow
-        ).into())
+    digits_parser(radix)
+    .recognize()
+    .map_result(move |number: I| 
+        number.with_str(|number| N::from_str(number, radix))
+        .map_err(|_| I::Error::number_overflow())
     )
 }
 
-pub(crate) fn int_parser<'a, N: Integer + Signed>(radix: u32) -> 
+pub(crate) fn int_parser<I, N: Integer + Signed>(radix: u32) -> 
 impl Parser<
-    Input = &'a str,
+    Input = I,
     Output = N::Inner,
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
+where
+    I: Input,
+    I::Error: NumberError
 {
-    '-'
+    char_parser('-')
     .optional()
-    .flat_map(move |minus| number_str_parser(radix, minus.is_some()))
-    .map_result(move |number: String| 
-        N::from_str(&number, radix)
-        .map_err(|_| StrParsingErrors::with_error_kind(
-            StrParsingErrorKind::NumberOverflow
-        ).into())
+    .and_then(digits_parser(radix))
+    .recognize()
+    .map_result(move |number: I| 
+        number.with_str(|number| N::from_str(number, radix))
+        .map_err(|_| I::Error::number_overflow())
     )
 }
 
-pub(crate) fn float_parser<'a, F: Float>() ->
+pub(crate) fn float_parser<I, F: Float>() ->
 impl Parser<
-    Input = &'a str,
+    Input = I,
     Output = F::Inner,
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
+where
+    I: Input,
+    I::Error: NumberError
 {
     let int_part =
-        '-' 
+        char_parser('-')
         .optional()
-        .flat_map(move |minus| number_str_parser(10, minus.is_some()))
+        .and_then(digits_parser(10))
         .optional()
     ;
 
     let decimal_part =
-        '.'
-        .then_parse(number_str_parser(10, false))
+        char_parser('.')
+        .and_then(digits_parser(10))
         .optional()
     ;
     int_part.and_then(decimal_part)
-    .map_result(|(int_part, decimal_part)|
-        F::from_str(&(
-            int_part.unwrap_or("".to_string()) + 
-            "." + 
-            &decimal_part.unwrap_or("".to_string())
-        ))
-        .map_err(|_| StrParsingErrors::with_error_kind(
-            StrParsingErrorKind::InvalidFloat
-        ).into())
+    .recognize()
+    .map_result(|number: I|
+        number.with_str(F::from_str)
+        .map_err(|_| I::Error::invalid_float())
     )
 }
 
-pub fn whitespaces_parser<'a>() -> 
+pub fn whitespaces_parser<I: Input>() -> 
 impl Parser<
-    Input = &'a str,
+    Input = I,
     Output = (),
-    Error = StrParsingErrors<'a>
+    Error = I::Error
 >
 {
     any_char()
     .verify(|c| c.is_whitespace())
-    .many()
-    .map(|_| ())
+    .fold_many((), |_, _| ())
 }
 
 pub fn string_literal_parser<'a>() -> 
@@ -170,8 +197,7 @@ impl Parser<
     // chracters -> many character
     let characters = 
         character
-        .many()
-        .map(|chars| String::from_iter(chars))
+        .many_into::<String>()
     ;
 
     // literal -> "chracters"
@@ -181,78 +207,222 @@ impl Parser<
     
 }
 
-struct AnyChar<'a> {
-    _private: &'a ()
+struct AnyChar<I> {
+    _marker: PhantomData<I>
 }
 
-impl<'a> Parser for AnyChar<'a> {
-    type Input = &'a str;
+impl<I: Input> Parser for AnyChar<I> {
+    type Input = I;
     type Output = char;
-    type Error = StrParsingErrors<'a>;
+    type Error = I::Error;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        let mut chars = input.data.chars();
-        let input_index = input.index;
-        chars.next()
-            .map_or(
+        let input = input.check_discarded()?;
+        let position = input.position;
+        match input.data.next_char() {
+            Some(Ok((c, len))) => {
+                let (_, rest) = input.take_split(len);
+                Ok((rest, c))
+            }
+            Some(Err(error)) => Err((
+                input,
+                ParsingError {
+                    error: error.into(),
+                    position
+                }
+            )),
+            None => {
+                let input = input.check_read_error::<I::Error>()?;
+                let error = if input.partial {
+                    ParsingErrorKind::Incomplete { needed: Needed::Size(1) }
+                }
+                else {
+                    I::Error::unexpected_end().into()
+                };
+
                 Err((
-                    input, 
+                    input,
                     ParsingError {
-                        error: StrParsingErrors::with_error_kind(
-                            StrParsingErrorKind::UnexpectedEnd
-                        ).into(),
-                        index: input_index
+                        error,
+                        position
                     }
-                )),
-                |c| Ok((
-                    ParserInput {
-                        data: chars.as_str(),
-                        index: input_index + 1
-                    },
-                    c
                 ))
-            )
+            }
+        }
     }
 }
 
 
-struct StringParser<'a> {
-    expected: &'a str
+pub(crate) struct Eof<I, E> {
+    pub(crate) _marker: PhantomData<(I, E)>
 }
 
-impl<'a> Parser for StringParser<'a> {
-    type Input = &'a str;
-    type Output = &'a str;
-    type Error = StrParsingErrors<'a>;
+impl<I, E> Parser for Eof<I, E>
+where
+    I: InputEnd + Clone,
+    E: InputError<I>
+{
+    type Input = I;
+    type Output = ();
+    type Error = E;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        let data = input.data;
-        let index = input.index;
-        if data.starts_with(self.expected) {
-            Ok((
-                ParserInput {
-                    data: &data[self.expected.len()..],
-                    index: input.index + self.expected.len()
-                }, 
-                self.expected
+        let position = input.position;
+        if !input.data.is_at_end() {
+            let error = E::expected_end(input.data.clone());
+            Err((
+                input,
+                ParsingError {
+                    error: error.into(),
+                    position
+                }
             ))
         }
         else {
+            let input = input.check_read_error::<E>()?;
+            if !input.partial {
+                return Ok((input, ()));
+            }
+
             Err((
                 input,
                 ParsingError {
-                    error: StrParsingErrors::with_error_kind(StrParsingErrorKind::UnexpectedString{
-                        expected: &self.expected,
-                        found: data
-                    }).into(),
-                    index: index
+                    error: ParsingErrorKind::Incomplete { needed: Needed::Unknown },
+                    position
                 }
-                
             ))
         }
     }
 }
 
+struct Commit<I> {
+    _marker: PhantomData<I>
+}
+
+impl<I: Input> Parser for Commit<I> {
+    type Input = I;
+    type Output = ();
+    type Error = I::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        input.data.commit();
+        Ok((input, ()))
+    }
+}
+
+struct Tag<I, T> {
+    expected: T,
+    _marker: PhantomData<I>
+}
+
+impl<I, T> Parser for Tag<I, T>
+where
+    I: Input + Compare<T>,
+    I::Error: TagError<T, I>,
+    T: Clone
+{
+    type Input = I;
+    type Output = I;
+    type Error = I::Error;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let input = input.check_discarded()?;
+        let position = input.position;
+        match input.data.compare(self.expected.clone()) {
+            CompareResult::Ok(len) => {
+                let (taken, rest) = input.take_split(len);
+                Ok((rest, taken))
+            }
+            CompareResult::Incomplete(needed) => {
+                let input = input.check_read_error::<I::Error>()?;
+                if !input.partial {
+                    return Err(self.unexpected_tag(input));
+                }
+
+                Err((
+                    input,
+                    ParsingError {
+                        error: ParsingErrorKind::Incomplete {
+                            needed: Needed::Size(needed)
+                        },
+                        position
+                    }
+                ))
+            }
+            CompareResult::Error => Err(self.unexpected_tag(input))
+        }
+    }
+}
+
+impl<I, T> Tag<I, T>
+where
+    I: Input + Compare<T>,
+    I::Error: TagError<T, I>,
+    T: Clone
+{
+    fn unexpected_tag(&self, input: ParserInput<I>) -> (ParserInput<I>, ParsingError<I::Error>) {
+        let position = input.position;
+        let error = I::Error::unexpected_tag(self.expected.clone(), input.data.clone());
+        (
+            input,
+            ParsingError {
+                error: error.into(),
+                position
+            }
+        )
+    }
+}
+
+impl<'a> Input for &'a str {
+    type Item = char;
+    type Iter = std::str::Chars<'a>;
+    type Error = StrParsingErrors<'a>;
+
+    fn iter_elements(&self) -> Self::Iter {
+        self.chars()
+    }
+
+    fn take_split(&self, count: usize) -> (Self, Self) {
+        self.split_at(count)
+    }
+
+    fn offset(&self, rest: &Self) -> usize {
+        rest.as_ptr() as usize - self.as_ptr() as usize
+    }
+
+    fn with_str<F, R>(&self, f: F) -> R
+    where F: FnOnce(&str) -> R
+    {
+        f(self)
+    }
+}
+
+impl InputEnd for &str {
+    fn is_at_end(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl InputSlice for &str {
+    fn consumed(&self, rest: &Self) -> Self {
+        &self[..self.len() - rest.len()]
+    }
+}
+
+impl<'b> Compare<&'b str> for &str {
+    fn compare(&self, tag: &'b str) -> CompareResult {
+        if self.starts_with(tag) {
+            CompareResult::Ok(tag.len())
+        }
+        else if tag.starts_with(*self) {
+            CompareResult::Incomplete(tag.len() - self.len())
+        }
+        else {
+            CompareResult::Error
+        }
+    }
+}
+
 impl<'a> Parser for &'a char {
     type Input = &'a str;
     type Output = char;
@@ -269,6 +439,40 @@ impl<'a> Parser for &'a str {
     type Error = StrParsingErrors<'a>;
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
-        string_parser(*self).parse(input)
+        tag(*self).parse(input)
+    }
+}
+
+impl<'a> Parser for &'a String {
+    type Input = &'a str;
+    type Output = &'a str;
+    type Error = StrParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        tag(self.as_str()).parse(input)
+    }
+}
+
+impl<'a> Parser for &'a RangeInclusive<char> {
+    type Input = &'a str;
+    type Output = char;
+    type Error = StrParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        any_char()
+        .verify(|c| self.contains(c))
+        .parse(input)
+    }
+}
+
+impl<'a, const N: usize> Parser for &'a [char; N] {
+    type Input = &'a str;
+    type Output = char;
+    type Error = StrParsingErrors<'a>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        any_char()
+        .verify(|c| self.contains(c))
+        .parse(input)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+use crate::{errors::{Needed, ParsingError, ParsingErrorKind, TokenParsingErrorKind, TokenParsingErrors}, parsers::Parser};
+
+use super::{Eof, ParserInput, ParserResult};
+
+
+pub fn any_token<'a, T: 'a>() ->
+impl Parser<
+    Input = &'a [T],
+    Output = &'a T,
+    Error = TokenParsingErrors<'a, T>
+>
+{
+    satisfy(|_| true)
+}
+
+pub fn eof<'a, T: 'a>() ->
+impl Parser<
+    Input = &'a [T],
+    Output = (),
+    Error = TokenParsingErrors<'a, T>
+>
+{
+    Eof {
+        _marker: PhantomData
+    }
+}
+
+pub fn token<'a, T: PartialEq + 'a>(expected: T) ->
+impl Parser<
+    Input = &'a [T],
+    Output = &'a T,
+    Error = TokenParsingErrors<'a, T>
+>
+{
+    satisfy(move |t| *t == expected)
+}
+
+pub fn satisfy<'a, T: 'a, Pred>(pred: Pred) ->
+impl Parser<
+    Input = &'a [T],
+    Output = &'a T,
+    Error = TokenParsingErrors<'a, T>
+>
+where
+    Pred: Fn(&T) -> bool
+{
+    Satisfy {
+        pred,
+        _marker: PhantomData
+    }
+}
+
+struct Satisfy<'a, T, Pred> {
+    pred: Pred,
+    _marker: PhantomData<&'a [T]>
+}
+
+impl<'a, T, Pred> Parser for Satisfy<'a, T, Pred>
+where
+    Pred: Fn(&T) -> bool
+{
+    type Input = &'a [T];
+    type Output = &'a T;
+    type Error = TokenParsingErrors<'a, T>;
+
+    fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error> {
+        let position = input.position;
+        match input.data.split_first() {
+            Some((token, rest)) if (self.pred)(token) => {
+                let mut position = position;
+                position.skip(1);
+                Ok((
+                    ParserInput {
+                        data: rest,
+                        position,
+                        partial: input.partial
+                    },
+                    token
+                ))
+            }
+            Some((token, _)) => Err((
+                input,
+                ParsingError {
+                    error: TokenParsingErrors::with_error_kind(
+                        TokenParsingErrorKind::UnexpectedToken {
+                            found: token,
+                            index: position.offset
+                        }
+                    ).into(),
+                    position
+                }
+            )),
+            None if input.partial => Err((
+                input,
+                ParsingError {
+                    error: ParsingErrorKind::Incomplete { needed: Needed::Size(1) },
+                    position
+                }
+            )),
+            None => Err((
+                input,
+                ParsingError {
+                    error: TokenParsingErrors::with_error_kind(
+                        TokenParsingErrorKind::UnexpectedEnd
+                    ).into(),
+                    position
+                }
+            ))
+        }
+    }
+}
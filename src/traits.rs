@@ -1,5 +1,5 @@
-use std::num::{ParseFloatError, ParseIntError};
-use crate::{combinators::*, errors::{ParsingErrorKind, StrParsingErrors}, parsers::{float_parser, ParserInput, ParserResult}};
+use std::{fmt::Debug, marker::PhantomData, num::{ParseFloatError, ParseIntError}, ops::{Bound, Range, RangeBounds}};
+use crate::{combinators::*, errors::{ParseReport, ParseReportKind, ParsingErrorKind, StrParsingErrors}, parsers::{float_parser, BoxedParser, ParserInput, ParserResult}};
 
 
 pub trait ParserError {
@@ -7,6 +7,129 @@ pub trait ParserError {
     fn append(self, new: Self) -> Self;
 }
 
+pub trait InputError<I>: ParserError {
+    fn unexpected_end() -> Self;
+    fn expected_end(found: I) -> Self;
+
+    // Only inputs that discard data on commit can fail with it
+    fn discarded_input() -> Self
+    where Self: Sized
+    {
+        Self::unexpected_end()
+    }
+
+    // The error that stopped reading the input, if it ended because of one
+    fn read_error(_input: &I) -> Option<Self>
+    where Self: Sized
+    {
+        None
+    }
+}
+
+pub trait TagError<T, I>: ParserError {
+    fn unexpected_tag(expected: T, found: I) -> Self;
+}
+
+pub trait LookaheadError<I>: ParserError {
+    fn unexpected_match(found: I) -> Self;
+}
+
+pub trait PermutationError: ParserError {
+    fn missing_members(names: Vec<&'static str>) -> Self;
+}
+
+pub trait NumberError: ParserError {
+    fn expecting_digit() -> Self;
+    fn number_overflow() -> Self;
+    fn invalid_float() -> Self;
+}
+
+pub trait ExprError: ParserError {
+    fn dangling_operator() -> Self;
+    fn non_associative_operator() -> Self;
+}
+
+pub trait AsChar: Copy {
+    fn as_char(self) -> char;
+
+    fn item_len(self) -> usize;
+}
+
+impl AsChar for char {
+    fn as_char(self) -> char {
+        self
+    }
+
+    fn item_len(self) -> usize {
+        self.len_utf8()
+    }
+}
+
+// Only right for ASCII: the text parsers decode byte inputs with `Input::next_char`
+impl AsChar for u8 {
+    fn as_char(self) -> char {
+        self as char
+    }
+
+    fn item_len(self) -> usize {
+        1
+    }
+}
+
+// Also implemented by token slices, which are not an Input
+pub trait InputEnd {
+    fn is_at_end(&self) -> bool;
+}
+
+// The part of the input consumed to reach `rest`, for token slices as well
+pub trait InputSlice {
+    fn consumed(&self, rest: &Self) -> Self;
+}
+
+// Sizes and offsets are expressed in the unit used by `AsChar::item_len`
+pub trait Input: Clone + InputEnd {
+    type Item: AsChar;
+    type Iter: Iterator<Item = Self::Item>;
+    type Error: InputError<Self>;
+
+    fn iter_elements(&self) -> Self::Iter;
+
+    fn take_split(&self, count: usize) -> (Self, Self);
+
+    fn offset(&self, rest: &Self) -> usize;
+
+    fn with_str<F, R>(&self, f: F) -> R
+    where F: FnOnce(&str) -> R
+    {
+        f(&self.iter_elements().map(AsChar::as_char).collect::<String>())
+    }
+
+    // The next char and the number of items it takes, byte inputs decode it from UTF-8
+    fn next_char(&self) -> Option<Result<(char, usize), Self::Error>> {
+        self.iter_elements()
+            .next()
+            .map(|item| Ok((item.as_char(), item.item_len())))
+    }
+
+    // Called once parsing will never backtrack before this input
+    fn commit(&self) {}
+
+    // Whether the data of this input was dropped by a later commit
+    fn is_discarded(&self) -> bool {
+        false
+    }
+}
+
+pub enum CompareResult {
+    Ok(usize),
+    Incomplete(usize),
+    Error,
+}
+
+pub trait Compare<T> {
+    fn compare(&self, tag: T) -> CompareResult;
+}
+
 pub trait Parser {
     type Input;
     type Output;
@@ -15,6 +138,38 @@ pub trait Parser {
 
     fn parse(&self, input: ParserInput<Self::Input>) -> ParserResult<Self::Input, Self::Output, Self::Error>;
 
+    fn parse_complete(&self, input: Self::Input) -> Result<Self::Output, ParseReport>
+    where
+        Self::Input: Input,
+        Self::Error: Debug
+    {
+        let (rest, output) = self.parse(input.into())
+            .map_err(|(_, error)| ParseReport::from(error))?;
+
+        if rest.data.iter_elements().next().is_some() {
+            return Err(ParseReport {
+                kind: ParseReportKind::TrailingInput,
+                position: rest.position
+            });
+        }
+
+        Ok(output)
+    }
+
+    fn parse_str<'a>(&self, input: &'a str) -> Result<Self::Output, ParseReport>
+    where
+        Self: Parser<Input = &'a str> + Sized,
+        Self::Error: Debug
+    {
+        self.parse_complete(input)
+    }
+
+    fn boxed<'p>(self) -> BoxedParser<'p, Self::Input, Self::Output, Self::Error>
+    where Self: Sized + 'p
+    {
+        Box::new(self)
+    }
+
     fn and_then<P>(self, other: P) -> AndThen<Self, P> 
     where
         Self: Sized,
@@ -80,6 +235,90 @@ pub trait Parser {
         }
     }
 
+    fn fold_many<R, F>(self, init: R, folder: F) -> FoldMany<Self, R, F>
+    where
+        Self: Sized,
+        R: Clone,
+        F: Fn(R, Self::Output) -> R
+    {
+        FoldMany {
+            p: self,
+            init,
+            folder
+        }
+    }
+
+    fn fold_many1<R, F>(self, init: R, folder: F) -> FoldMany1<Self, R, F>
+    where
+        Self: Sized,
+        R: Clone,
+        F: Fn(R, Self::Output) -> R
+    {
+        FoldMany1 {
+            p: self,
+            init,
+            folder
+        }
+    }
+
+    fn many_into<C>(self) -> ManyInto<Self, C>
+    where
+        Self: Sized,
+        C: Default + Extend<Self::Output>
+    {
+        ManyInto {
+            p: self,
+            _marker: PhantomData
+        }
+    }
+
+    fn count(self, count: usize) -> Repeat<Self>
+    where Self: Sized
+    {
+        Repeat {
+            p: self,
+            min: count,
+            max: Some(count)
+        }
+    }
+
+    fn repeat<R>(self, range: R) -> Repeat<Self>
+    where
+        Self: Sized,
+        R: RangeBounds<usize>
+    {
+        let min = match range.start_bound() {
+            Bound::Included(&min) => min,
+            Bound::Excluded(&min) => min + 1,
+            Bound::Unbounded => 0
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&max) => Some(max),
+            Bound::Excluded(&max) => Some(max.checked_sub(1).expect("repeat range must not be empty")),
+            Bound::Unbounded => None
+        };
+        assert!(max.is_none_or(|max| min <= max), "repeat range must not be empty");
+
+        Repeat {
+            p: self,
+            min,
+            max
+        }
+    }
+
+    fn many_till<P>(self, end: P) -> ManyTill<Self, P>
+    where
+        Self: Sized,
+        Self::Input: Clone,
+        P: Parser<Input = Self::Input>,
+        P::Error: Into<Self::Error>
+    {
+        ManyTill {
+            p: self,
+            end
+        }
+    }
+
     fn then_consume<P>(self, other: P) -> 
     impl Parser<
         Input = Self::Input, 
@@ -88,10 +327,10 @@ pub trait Parser {
     > 
     where
         Self: Sized,
-        P: Parser<Input = Self::Input, Error = Self::Error>,
+        P: Parser<Input = Self::Input>,
+        P::Error: Into<Self::Error>
     {
-        self.and_then(other)
-            .map(|(output, _)| output)
+        terminated(self, other)
     }
 
 
@@ -103,10 +342,75 @@ pub trait Parser {
     > 
     where
         Self: Sized,
-        P: Parser<Input = Self::Input, Error = Self::Error>,
+        P: Parser<Input = Self::Input>,
+        P::Error: Into<Self::Error>
     {
-        self.and_then(other)
-            .map(|(_, output)| output)
+        preceded(self, other)
+    }
+
+    fn cut(self) -> Cut<Self>
+    where Self: Sized
+    {
+        Cut(self)
+    }
+
+    fn peek(self) -> Peek<Self>
+    where
+        Self: Sized,
+        Self::Input: Clone
+    {
+        Peek(self)
+    }
+
+    fn followed_by<P>(self, other: P) -> 
+    impl Parser<
+        Input = Self::Input, 
+        Output = Self::Output,
+        Error = Self::Error
+    > 
+    where
+        Self: Sized,
+        Self::Input: Clone,
+        P: Parser<Input = Self::Input>,
+        P::Error: Into<Self::Error>
+    {
+        terminated(self, other.peek())
+    }
+
+    fn not_followed_by<P>(self, other: P) -> 
+    impl Parser<
+        Input = Self::Input, 
+        Output = Self::Output,
+        Error = Self::Error
+    > 
+    where
+        Self: Sized,
+        Self::Input: InputSlice + Clone,
+        P: Parser<Input = Self::Input>,
+        P::Error: LookaheadError<Self::Input> + Into<Self::Error>
+    {
+        terminated(self, not(other))
+    }
+
+    fn all_consuming(self) -> AllConsuming<Self>
+    where
+        Self: Sized,
+        Self::Input: InputEnd + Clone,
+        Self::Error: InputError<Self::Input>
+    {
+        AllConsuming(self)
+    }
+
+    fn surrounded_by<P>(self, surrounding: P) -> SurroundedBy<Self, P>
+    where
+        Self: Sized,
+        P: Parser<Input = Self::Input>,
+        P::Error: Into<Self::Error>
+    {
+        SurroundedBy {
+            p: self,
+            surrounding
+        }
     }
 
     fn verify<Pred>(self, pred: Pred) -> Verify<Self, Pred>
@@ -148,10 +452,100 @@ pub trait Parser {
         Self: Sized,
         SepP: Parser<Input = Self::Input>
     {
-        SepBy { p: self, separator }
+        SepBy(SepByWithSeparators { p: self, separator, allow_empty: false, allow_trailing: false })
+    }
+
+    fn sep_by0<SepP>(self, separator: SepP) -> SepBy<Self, SepP>
+    where
+        Self: Sized,
+        SepP: Parser<Input = Self::Input>
+    {
+        SepBy(SepByWithSeparators { p: self, separator, allow_empty: true, allow_trailing: false })
+    }
+
+    fn sep_by1<SepP>(self, separator: SepP) -> SepBy<Self, SepP>
+    where
+        Self: Sized,
+        SepP: Parser<Input = Self::Input>
+    {
+        self.sep_by(separator)
+    }
+
+    fn sep_end_by<SepP>(self, separator: SepP) -> SepBy<Self, SepP>
+    where
+        Self: Sized,
+        SepP: Parser<Input = Self::Input>
+    {
+        SepBy(SepByWithSeparators { p: self, separator, allow_empty: true, allow_trailing: true })
+    }
+
+    fn sep_by_with_separators<SepP>(self, separator: SepP) -> SepByWithSeparators<Self, SepP>
+    where
+        Self: Sized,
+        SepP: Parser<Input = Self::Input>
+    {
+        SepByWithSeparators { p: self, separator, allow_empty: true, allow_trailing: true }
+    }
+
+    fn chain_left<OpP>(self, op: OpP) -> ChainLeft<Self, OpP>
+    where
+        Self: Sized,
+        OpP: Parser<Input = Self::Input>,
+        OpP::Error: Into<Self::Error>,
+        OpP::Output: Fn(Self::Output, Self::Output) -> Self::Output
+    {
+        ChainLeft { p: self, op }
+    }
+
+    fn chain_right<OpP>(self, op: OpP) -> ChainRight<Self, OpP>
+    where
+        Self: Sized,
+        OpP: Parser<Input = Self::Input>,
+        OpP::Error: Into<Self::Error>,
+        OpP::Output: Fn(Self::Output, Self::Output) -> Self::Output
+    {
+        ChainRight { p: self, op }
+    }
+
+    fn spanned(self) -> WithSpan<Self>
+    where Self: Sized
+    {
+        WithSpan(self)
+    }
+
+    fn recognize(self) -> Recognize<Self>
+    where
+        Self: Sized,
+        Self::Input: Input
+    {
+        Recognize(self)
+    }
+
+    fn map_with_span<F, R>(self, mapper: F) -> MapWithSpan<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output, Range<usize>) -> R
+    {
+        MapWithSpan {
+            p: self,
+            mapper
+        }
     }
 }
 
+pub trait PermutationMember {
+    type Parser: Parser;
+    type Output;
+
+    fn parser(&self) -> &Self::Parser;
+
+    fn name(&self) -> &'static str;
+
+    fn is_required(&self) -> bool;
+
+    fn finish(&self, output: Option<<Self::Parser as Parser>::Output>) -> Self::Output;
+}
+
 pub trait Number {
     type Inner;
 }
@@ -169,7 +563,14 @@ impl<T: not_signed::NotSigned> Signed for T {}
 impl<T: Unsigned> not_signed::NotSigned for T {}
 
 pub trait ParseableInteger<'a>: Integer {
-    fn str_parser(radix: u32) -> impl Parser<Input = &'a str, Output = Self::Inner, Error = StrParsingErrors<'a>>;
+    fn parser<I>(radix: u32) -> impl Parser<Input = I, Output = Self::Inner, Error = I::Error>
+    where
+        I: Input,
+        I::Error: NumberError;
+
+    fn str_parser(radix: u32) -> impl Parser<Input = &'a str, Output = Self::Inner, Error = StrParsingErrors<'a>> {
+        Self::parser(radix)
+    }
 }
 
 pub trait Float: Number {
@@ -177,11 +578,22 @@ pub trait Float: Number {
 }
 
 pub trait FloatParser<'a>: Float {
-    fn str_parser() -> impl Parser<Input = &'a str, Output = Self::Inner, Error = StrParsingErrors<'a>>;
+    fn parser<I>() -> impl Parser<Input = I, Output = Self::Inner, Error = I::Error>
+    where
+        I: Input,
+        I::Error: NumberError;
+
+    fn str_parser() -> impl Parser<Input = &'a str, Output = Self::Inner, Error = StrParsingErrors<'a>> {
+        Self::parser()
+    }
 }
 
 impl<'a, F: Float> FloatParser<'a> for F {
-    fn str_parser() -> impl Parser<Input = &'a str, Output = Self::Inner, Error = StrParsingErrors<'a>> {
-        float_parser::<Self>()
+    fn parser<I>() -> impl Parser<Input = I, Output = Self::Inner, Error = I::Error>
+    where
+        I: Input,
+        I::Error: NumberError
+    {
+        float_parser::<I, Self>()
     }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use parsecomx::{errors::ParsingErrorKind, parsers::{any_char, reader::ReaderInput, ParserInput}, traits::Parser};
+
+#[test]
+fn any_char_decodes_utf8_bytes() {
+    let src = "é😀a".as_bytes();
+    let (rest, output) = any_char::<&[u8]>().many().parse(src.into()).unwrap();
+    assert_eq!(output, vec!['é', '😀', 'a']);
+    assert_eq!(rest.position().offset, src.len());
+}
+
+#[test]
+fn any_char_rejects_invalid_utf8() {
+    let src: &[u8] = &[b'a', 0xff];
+    let (_, error) = any_char::<&[u8]>().and_then(any_char()).parse(src.into()).unwrap_err();
+    assert!(matches!(error.error, ParsingErrorKind::Custom(_)));
+    assert_eq!(error.position.offset, 1);
+}
+
+#[test]
+fn any_char_needs_the_rest_of_a_partial_char() {
+    let src = &"😀".as_bytes()[..2];
+    let (_, error) = any_char::<&[u8]>().parse(ParserInput::partial(src)).unwrap_err();
+    assert!(error.is_incomplete());
+}
+
+#[test]
+fn any_char_decodes_utf8_from_a_reader() {
+    let input = ReaderInput::new("é😀".as_bytes());
+    let (_, output) = any_char().many().parse(ParserInput::from(input)).unwrap();
+    assert_eq!(output, vec!['é', '😀']);
+}
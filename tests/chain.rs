@@ -0,0 +1,27 @@
+use parsecomx::{errors::StrParsingErrors, parsers::any_char, traits::Parser};
+
+fn digit<'a>() -> impl Parser<Input = &'a str, Output = i64, Error = StrParsingErrors<'a>> {
+    any_char().verify(|c| c.is_ascii_digit()).map(|c| c.to_digit(10).unwrap() as i64)
+}
+
+#[test]
+fn chain_left_folds_from_the_left() {
+    let sub = (&'-').map(|_| |a, b| a - b);
+
+    assert_eq!(digit().chain_left(sub).parse_str("9-3-2").unwrap(), 4);
+}
+
+#[test]
+fn chain_right_folds_from_the_right() {
+    let sub = (&'-').map(|_| |a, b| a - b);
+
+    assert_eq!(digit().chain_right(sub).parse_str("9-3-2").unwrap(), 8);
+}
+
+#[test]
+fn chain_right_handles_long_chains() {
+    let add = (&'+').map(|_| |a, b| a + b);
+    let src = vec!["1"; 100_000].join("+");
+
+    assert_eq!(digit().chain_right(add).parse_str(&src).unwrap(), 100_000);
+}
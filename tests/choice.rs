@@ -0,0 +1,17 @@
+use parsecomx::{errors::{ParsingErrorKind, StrParsingErrors}, parsers::{choice, BoxedParser}, traits::Parser};
+
+#[test]
+fn choice_tries_each_parser() {
+    let parsers: Vec<BoxedParser<&str, &str, StrParsingErrors>> = vec![Box::new("a"), Box::new("b")];
+
+    assert_eq!(choice(parsers).parse_str("b").unwrap(), "b");
+}
+
+#[test]
+fn empty_choice_fails() {
+    let parser = choice(Vec::<BoxedParser<&str, &str, StrParsingErrors>>::new());
+
+    let (_, error) = parser.parse("a".into()).unwrap_err();
+    assert!(matches!(error.error, ParsingErrorKind::VerifyError));
+    assert_eq!(error.position.offset, 0);
+}
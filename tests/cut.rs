@@ -0,0 +1,32 @@
+use parsecomx::{errors::{ParsingErrorKind, StrParsingErrors}, parsers::{any_char, tag}, traits::Parser};
+
+fn digit<'a>() -> impl Parser<Input = &'a str, Output = u32, Error = StrParsingErrors<'a>> {
+    any_char().verify(|c| c.is_ascii_digit()).map(|c| c.to_digit(10).unwrap())
+}
+
+// The cut error keeps the error of the parser that failed
+fn is_custom_cut<E>(error: &ParsingErrorKind<E>) -> bool {
+    matches!(error, ParsingErrorKind::Cut(error) if matches!(**error, ParsingErrorKind::Custom(_)))
+}
+
+#[test]
+fn many_till_propagates_end_cut() {
+    let parser = any_char::<&str>().many_till(tag("*").and_then(tag("/").cut()));
+
+    let (_, error) = parser.parse("ab*x*/".into()).unwrap_err();
+    assert!(is_custom_cut(&error.error));
+    assert_eq!(error.position.offset, 3);
+}
+
+#[test]
+fn chain_propagates_operator_cut() {
+    let add = tag("+").and_then(tag("!").cut()).map(|_| |a, b| a + b);
+
+    let (_, error) = digit().chain_left(&add).parse("1+!2+3".into()).unwrap_err();
+    assert!(is_custom_cut(&error.error));
+    assert_eq!(error.position.offset, 5);
+
+    let (_, error) = digit().chain_right(&add).parse("1+!2+3".into()).unwrap_err();
+    assert!(is_custom_cut(&error.error));
+    assert_eq!(error.position.offset, 5);
+}
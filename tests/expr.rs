@@ -0,0 +1,61 @@
+use parsecomx::{errors::{ParsingError, StrParsingErrors}, parsers::{any_char, expr::{expr, Assoc, ExprParser}}, traits::Parser};
+
+type Atom<'a> = Box<dyn Parser<Input = &'a str, Output = String, Error = StrParsingErrors<'a>> + 'a>;
+
+// Builds s-expressions, to check how the operators were grouped
+fn arithmetic<'a>() -> ExprParser<'a, Atom<'a>> {
+    let atom: Atom<'a> = Box::new(any_char().verify(|c| c.is_ascii_digit()).map(String::from));
+
+    expr(atom)
+        .infix(&'=', Assoc::None, 1, |lhs, _, rhs| format!("(= {lhs} {rhs})"))
+        .infix(&'+', Assoc::Left, 2, |lhs, _, rhs| format!("(+ {lhs} {rhs})"))
+        .infix(&'*', Assoc::Left, 3, |lhs, _, rhs| format!("(* {lhs} {rhs})"))
+        .infix(&'^', Assoc::Right, 4, |lhs, _, rhs| format!("(^ {lhs} {rhs})"))
+        .prefix(&'-', 5, |_, operand| format!("(- {operand})"))
+        .postfix(&'!', 6, |operand, _| format!("(! {operand})"))
+}
+
+fn parse(src: &str) -> Result<String, ParsingError<StrParsingErrors<'_>>> {
+    arithmetic()
+        .parse(src.into())
+        .map(|(_, output)| output)
+        .map_err(|(_, error)| error)
+}
+
+#[test]
+fn binding_powers() {
+    assert_eq!(parse("1+2*3").unwrap(), "(+ 1 (* 2 3))");
+    assert_eq!(parse("1*2+3").unwrap(), "(+ (* 1 2) 3)");
+    assert_eq!(parse("1=2+3").unwrap(), "(= 1 (+ 2 3))");
+}
+
+#[test]
+fn associativity() {
+    assert_eq!(parse("1+2+3").unwrap(), "(+ (+ 1 2) 3)");
+    assert_eq!(parse("2^3^2").unwrap(), "(^ 2 (^ 3 2))");
+}
+
+#[test]
+fn prefix_and_postfix() {
+    assert_eq!(parse("-2!").unwrap(), "(- (! 2))");
+    assert_eq!(parse("-2^2").unwrap(), "(^ (- 2) 2)");
+    assert_eq!(parse("2!*3").unwrap(), "(* (! 2) 3)");
+}
+
+#[test]
+fn non_associative_operator() {
+    let error = parse("1=2=3").unwrap_err();
+    assert!(format!("{:?}", error.error).contains("NonAssociativeOperator"));
+    assert_eq!(error.position.offset, 3);
+}
+
+#[test]
+fn dangling_operator() {
+    let error = parse("1+").unwrap_err();
+    assert!(format!("{:?}", error.error).contains("DanglingOperator"));
+    assert_eq!(error.position.offset, 1);
+
+    let error = parse("-").unwrap_err();
+    assert!(format!("{:?}", error.error).contains("DanglingOperator"));
+    assert_eq!(error.position.offset, 0);
+}
@@ -0,0 +1,29 @@
+use parsecomx::{errors::{ParsingError, ParsingErrorKind, StrParsingErrors}, parsers::{any_char, from_fn, ParserInput, ParserResult}, traits::Parser};
+
+#[test]
+fn literals_by_reference() {
+    let keyword = String::from("let");
+    let lower = 'a'..='z';
+    let signs = ['+', '-'];
+
+    assert_eq!((&keyword).parse_str("let").unwrap(), "let");
+    assert_eq!((&lower).many1().parse_str("abc").unwrap(), vec!['a', 'b', 'c']);
+    assert_eq!((&signs).parse_str("-").unwrap(), '-');
+    assert!((&lower).parse_str("A").is_err());
+}
+
+fn even_digit<'a>(input: ParserInput<&'a str>) -> ParserResult<&'a str, u32, StrParsingErrors<'a>> {
+    let position = input.position();
+    match any_char().map(|c: char| c.to_digit(10)).parse(input)? {
+        (rest, Some(digit)) if digit % 2 == 0 => Ok((rest, digit)),
+        (rest, _) => Err((rest, ParsingError { error: ParsingErrorKind::VerifyError, position }))
+    }
+}
+
+#[test]
+fn from_fn_combines_with_combinators() {
+    let digits = from_fn(even_digit).sep_by(&',');
+
+    assert_eq!(digits.parse_str("2,4,8").unwrap(), vec![2, 4, 8]);
+    assert!(digits.parse_str("3").is_err());
+}
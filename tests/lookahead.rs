@@ -0,0 +1,29 @@
+use parsecomx::{parsers::{not, tag, token::{any_token, token}}, traits::Parser};
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident,
+    Dot,
+    Number
+}
+
+#[test]
+fn not_followed_by_on_str() {
+    let keyword = tag("if").not_followed_by(tag("_"));
+
+    assert!(keyword.parse("if x".into()).is_ok());
+    assert!(keyword.parse("if_x".into()).is_err());
+}
+
+#[test]
+fn not_followed_by_on_tokens() {
+    let tokens = [Token::Ident, Token::Dot, Token::Number];
+    let ident = token(Token::Ident).not_followed_by(token(Token::Dot));
+
+    let (_, error) = ident.parse(tokens.as_slice().into()).unwrap_err();
+    assert_eq!(error.position.offset, 1);
+
+    let (rest, _) = not(token(Token::Number)).parse(tokens.as_slice().into()).unwrap();
+    assert_eq!(rest.position().offset, 0);
+    assert!(not(any_token::<Token>()).parse([].as_slice().into()).is_ok());
+}
@@ -0,0 +1,52 @@
+use parsecomx::{errors::{Needed, ParsingErrorKind}, parsers::{eof, tag, ParserInput}, traits::Parser};
+
+fn needed<E>(error: &ParsingErrorKind<E>) -> Option<Needed> {
+    match error {
+        ParsingErrorKind::Incomplete { needed } => Some(*needed),
+        _ => None
+    }
+}
+
+#[test]
+fn tag_needs_the_rest_of_a_prefix() {
+    let (_, error) = tag("hello").parse(ParserInput::partial("hel")).unwrap_err();
+    assert_eq!(needed(&error.error), Some(Needed::Size(2)));
+
+    let (_, error) = tag("hello").parse(ParserInput::from("hel")).unwrap_err();
+    assert!(!error.is_incomplete());
+}
+
+#[test]
+fn eof_is_unknown_on_partial_input() {
+    let (_, error) = eof::<&str>().parse(ParserInput::partial("")).unwrap_err();
+    assert_eq!(needed(&error.error), Some(Needed::Unknown));
+
+    assert!(eof::<&str>().parse(ParserInput::from("")).is_ok());
+}
+
+#[test]
+fn many_passes_incomplete_through() {
+    let (_, error) = (&'a').many().parse(ParserInput::partial("aa")).unwrap_err();
+    assert!(error.is_incomplete());
+
+    let (_, output) = (&'a').many().parse(ParserInput::from("aa")).unwrap();
+    assert_eq!(output, vec!['a', 'a']);
+}
+
+#[test]
+fn sep_by_passes_incomplete_through() {
+    let (_, error) = (&'a').sep_by(&',').parse(ParserInput::partial("a,a")).unwrap_err();
+    assert!(error.is_incomplete());
+
+    let (_, error) = (&'a').sep_by(&',').parse(ParserInput::partial("a,")).unwrap_err();
+    assert!(error.is_incomplete());
+}
+
+#[test]
+fn optional_passes_incomplete_through() {
+    let (_, error) = tag("ab").optional().parse(ParserInput::partial("a")).unwrap_err();
+    assert_eq!(needed(&error.error), Some(Needed::Size(1)));
+
+    let (_, output) = tag("ab").optional().parse(ParserInput::from("a")).unwrap();
+    assert_eq!(output, None);
+}
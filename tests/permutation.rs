@@ -0,0 +1,42 @@
+use parsecomx::{parsers::{member, optional_member, permutation}, traits::Parser};
+
+#[test]
+fn members_in_any_order() {
+    let parser = permutation((member("a", &'a'), member("b", &'b'), optional_member("c", &'c')));
+
+    assert_eq!(parser.parse_str("cba").unwrap(), ('a', 'b', Some('c')));
+    assert_eq!(parser.parse_str("abc").unwrap(), ('a', 'b', Some('c')));
+    assert_eq!(parser.parse_str("ba").unwrap(), ('a', 'b', None));
+}
+
+#[test]
+fn members_are_parsed_once() {
+    let parser = permutation((member("a", &'a'), optional_member("c", &'c')));
+
+    let (rest, output) = parser.parse("aca".into()).unwrap();
+    assert_eq!(output, ('a', Some('c')));
+    assert_eq!(rest.position().offset, 2);
+}
+
+#[test]
+fn missing_members_are_named() {
+    let parser = permutation((member("a", &'a'), member("b", &'b'), optional_member("c", &'c')));
+
+    let (_, error) = parser.parse("ca".into()).unwrap_err();
+    assert!(format!("{:?}", error.error).contains(r#"MissingMembers { names: ["b"] }"#));
+
+    let (_, error) = parser.parse("".into()).unwrap_err();
+    assert!(format!("{:?}", error.error).contains(r#"MissingMembers { names: ["a", "b"] }"#));
+}
+
+#[test]
+fn member_cut_stops_the_permutation() {
+    let parser = permutation((
+        member("a", &'a'),
+        optional_member("b", (&'b').and_then((&'!').cut()).map(|(b, _)| b))
+    ));
+
+    let (_, error) = parser.parse("ab?".into()).unwrap_err();
+    assert!(error.is_cut());
+    assert_eq!(error.position.offset, 2);
+}
@@ -0,0 +1,53 @@
+use parsecomx::{errors::ParsingErrorKind, parsers::{any_char, tag, ParserInput, Position}, traits::Parser};
+
+const SRC: &str = "a\r\nb😀c";
+
+fn position(offset: usize, char_offset: usize, line: usize, column: usize) -> Position {
+    Position { offset, char_offset, line, column }
+}
+
+#[test]
+fn any_char_tracks_positions() {
+    let parser = any_char::<&str>();
+    let mut input = ParserInput::from(SRC);
+    let mut positions = Vec::new();
+    while let Ok((rest, _)) = parser.parse(input) {
+        positions.push(rest.position());
+        assert!(SRC.is_char_boundary(rest.position().offset));
+        input = rest;
+    }
+
+    assert_eq!(positions, vec![
+        position(1, 1, 1, 2),
+        position(2, 2, 1, 3),
+        position(3, 3, 2, 1),
+        position(4, 4, 2, 2),
+        position(8, 5, 2, 3),
+        position(9, 6, 2, 4),
+    ]);
+}
+
+#[test]
+fn tag_tracks_positions() {
+    let (rest, output) = tag("a\r\nb😀").parse(SRC.into()).unwrap();
+    assert_eq!(output, "a\r\nb😀");
+    assert_eq!(rest.position(), position(8, 5, 2, 3));
+    assert_eq!(&SRC[rest.position().offset..], "c");
+}
+
+#[test]
+fn error_positions_can_slice_the_input() {
+    let (_, error) = any_char::<&str>()
+        .many()
+        .and_then(any_char())
+        .parse(SRC.into())
+        .unwrap_err();
+
+    assert_eq!(error.position, position(9, 6, 2, 4));
+    assert_eq!(&SRC[error.position.offset..], "");
+
+    let (_, error) = tag("a\r\nb😀").and_then(tag("d")).parse(SRC.into()).unwrap_err();
+    assert_eq!(error.position, position(8, 5, 2, 3));
+    assert_eq!(&SRC[error.position.offset..], "c");
+    assert!(matches!(error.error, ParsingErrorKind::Custom(_)));
+}
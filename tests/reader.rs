@@ -0,0 +1,80 @@
+use std::io::{self, Read};
+
+use parsecomx::{errors::{ParsingErrorKind, ReaderParsingErrors}, parsers::{alt, commit, reader::ReaderInput, tag, ParserInput}, traits::{ParseableInteger, Parser}};
+
+#[test]
+fn backtracking_past_a_commit_is_a_cut_error() {
+    let parser = alt((
+        tag("ab").then_parse(commit()).then_parse(tag("cd")),
+        tag("abcx")
+    ));
+
+    let input = ReaderInput::new("abcxyz".as_bytes());
+    let (_, error) = parser.parse(ParserInput::from(input)).unwrap_err();
+    assert!(error.is_cut());
+    assert_eq!(error.position.offset, 0);
+}
+
+#[test]
+fn backtracking_before_a_commit_is_allowed() {
+    let parser = alt((
+        tag("ab").then_parse(tag("cd")),
+        tag("abcx")
+    ))
+    .then_parse(commit())
+    .then_parse(tag("yz"));
+
+    let input = ReaderInput::new("abcxyz".as_bytes());
+    assert!(parser.parse(ParserInput::from(input)).is_ok());
+}
+
+// Returns its data in one read, then fails
+struct FailingReader(&'static [u8]);
+
+impl Read for FailingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.0.is_empty() {
+            return Err(io::Error::other("disk failure"));
+        }
+
+        let len = self.0.len().min(buf.len());
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+fn is_io_cut(error: &ParsingErrorKind<ReaderParsingErrors>) -> bool {
+    matches!(error, ParsingErrorKind::Cut(error) if format!("{error:?}").contains("Io {"))
+}
+
+#[test]
+fn read_errors_are_not_the_end_of_input() {
+    let parser = tag("1").sep_end_by(tag(","));
+    let (_, error) = parser.parse(ReaderInput::new(FailingReader(b"1,")).into()).unwrap_err();
+    assert!(is_io_cut(&error.error));
+    assert_eq!(error.position.offset, 2);
+
+    let parser = u32::parser(10).then_consume(tag(",")).all_consuming();
+    let (_, error) = parser.parse(ReaderInput::new(FailingReader(b"1,")).into()).unwrap_err();
+    assert!(is_io_cut(&error.error));
+
+    assert!(parser.parse_complete(ReaderInput::new(FailingReader(b"1,"))).is_err());
+}
+
+#[test]
+fn data_read_before_an_error_parses() {
+    let (_, output) = tag("1,").parse(ReaderInput::new(FailingReader(b"1,")).into()).unwrap();
+    assert_eq!(output.to_vec(), b"1,");
+}
+
+#[test]
+fn recognized_bytes_survive_a_commit() {
+    let parser = tag("abc")
+        .recognize()
+        .then_consume(tag(";"))
+        .then_consume(commit());
+
+    let (_, output) = parser.parse(ReaderInput::new("abc;def".as_bytes()).into()).unwrap();
+    assert_eq!(output.to_vec(), b"abc");
+}
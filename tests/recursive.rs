@@ -0,0 +1,46 @@
+use parsecomx::{parsers::{delimited, recursive}, traits::Parser};
+
+#[derive(Debug, PartialEq)]
+struct Tree(Vec<Tree>);
+
+fn nested(depth: usize) -> String {
+    "[".repeat(depth) + &"]".repeat(depth)
+}
+
+fn depth(tree: &Tree) -> usize {
+    1 + tree.0.iter().map(depth).max().unwrap_or(0)
+}
+
+fn parse_nested(depth: usize) -> Tree {
+    let src = nested(depth);
+    let parser = recursive(|this| delimited(&'[', this.sep_by0(&','), &']').map(Tree));
+
+    parser.parse_str(&src).unwrap()
+}
+
+#[test]
+fn recursive_parses_siblings() {
+    let parser = recursive(|this| delimited(&'[', this.sep_by0(&','), &']').map(Tree));
+
+    let tree = parser.parse_str("[[],[[]],[]]").unwrap();
+    assert_eq!(tree, Tree(vec![Tree(vec![]), Tree(vec![Tree(vec![])]), Tree(vec![])]));
+}
+
+// Test threads have a 2 MiB stack, the depth documented in the README
+#[test]
+fn recursive_supports_documented_depth() {
+    let tree = parse_nested(256);
+    assert_eq!(depth(&tree), 256);
+}
+
+#[test]
+fn recursive_depth_grows_with_stack_size() {
+    let tree = std::thread::Builder::new()
+        .stack_size(16 << 20)
+        .spawn(|| parse_nested(2000))
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(depth(&tree), 2000);
+}
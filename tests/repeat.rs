@@ -0,0 +1,23 @@
+use parsecomx::{parsers::any_char, traits::Parser};
+
+#[test]
+fn repeat_stops_at_the_maximum() {
+    let (rest, output) = any_char::<&str>().repeat(2..4).parse("abcde".into()).unwrap();
+    assert_eq!(output, vec!['a', 'b', 'c']);
+    assert_eq!(rest.position().offset, 3);
+
+    assert!(any_char::<&str>().repeat(2..=4).parse("a".into()).is_err());
+}
+
+#[test]
+#[should_panic(expected = "repeat range must not be empty")]
+fn repeat_rejects_empty_exclusive_range() {
+    let _ = any_char::<&str>().repeat(3..3);
+}
+
+#[test]
+#[should_panic(expected = "repeat range must not be empty")]
+fn repeat_rejects_min_above_max() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = any_char::<&str>().repeat(4..=2);
+}
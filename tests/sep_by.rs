@@ -0,0 +1,51 @@
+use parsecomx::{errors::StrParsingErrors, parsers::any_char, traits::{Parser, ParserError}};
+
+fn digit<'a>() -> impl Parser<Input = &'a str, Output = char, Error = StrParsingErrors<'a>> {
+    any_char().verify(|c| c.is_ascii_digit())
+}
+
+#[test]
+fn sep_by0_accepts_an_empty_list() {
+    let (rest, output) = digit().sep_by0(&',').parse("x".into()).unwrap();
+    assert!(output.is_empty());
+    assert_eq!(rest.position().offset, 0);
+}
+
+#[test]
+fn sep_by0_reports_a_partial_first_element() {
+    let (_, error) = (digit(), digit()).sep_by0(&',').parse("1x".into()).unwrap_err();
+    assert_eq!(error.position.offset, 1);
+}
+
+#[test]
+fn sep_end_by_accepts_a_trailing_separator() {
+    let (rest, output) = digit().sep_end_by(&',').parse("1,2,x".into()).unwrap();
+    assert_eq!(output, vec!['1', '2']);
+    assert_eq!(rest.position().offset, 4);
+}
+
+#[test]
+fn sep_by_with_separators_returns_the_separators() {
+    let separator = any_char().verify(|c| *c == ',' || *c == ';');
+    let (_, (output, separators)) = digit().sep_by_with_separators(separator).parse("1,2;3".into()).unwrap();
+    assert_eq!(output, vec!['1', '2', '3']);
+    assert_eq!(separators, vec![',', ';']);
+}
+
+#[derive(Debug)]
+struct SeparatorError;
+
+impl ParserError for SeparatorError {
+    fn append(self, _: Self) -> Self {
+        self
+    }
+}
+
+#[test]
+fn sep_by_propagates_separator_cut() {
+    let separator = (&',').and_then((&' ').cut()).map_err(|_| SeparatorError);
+
+    let (_, error) = digit().sep_by(separator).parse("1, 2,x".into()).unwrap_err();
+    assert!(error.is_cut());
+    assert_eq!(error.position.offset, 5);
+}